@@ -0,0 +1,8 @@
+#![no_std]
+//! Helpers shared by the contracts in this workspace. This crate is not a
+//! contract itself and is not deployed.
+
+pub mod strbuf;
+
+#[cfg(test)]
+mod test;
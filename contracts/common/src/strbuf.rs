@@ -0,0 +1,88 @@
+//! Building a `soroban_sdk::String` from parts.
+//!
+//! `String` has no concatenation in `no_std`, since there is no allocator to
+//! grow it. `StrBuf` collects byte slices and other `String`s into a fixed
+//! buffer of `N` bytes on the stack and converts the result once.
+
+use soroban_sdk::{Env, String};
+
+/// The parts pushed to a `StrBuf` do not fit in its `N` bytes.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct CapacityExceeded;
+
+/// Fixed-capacity buffer for concatenating string parts.
+///
+/// Pushes can be chained. A part that does not fit is dropped and marks the
+/// buffer as overflowed, so `build` fails instead of returning a truncated
+/// string.
+pub struct StrBuf<const N: usize> {
+    buf: [u8; N],
+    len: usize,
+    overflowed: bool,
+}
+
+impl<const N: usize> StrBuf<N> {
+    pub fn new() -> Self {
+        Self {
+            buf: [0; N],
+            len: 0,
+            overflowed: false,
+        }
+    }
+
+    /// Append raw bytes, such as a literal separator.
+    pub fn push_bytes(&mut self, bytes: &[u8]) -> &mut Self {
+        if let Some(dest) = self.reserve(bytes.len()) {
+            dest.copy_from_slice(bytes);
+        }
+        self
+    }
+
+    /// Append the bytes of `s`.
+    pub fn push_string(&mut self, s: &String) -> &mut Self {
+        if let Some(dest) = self.reserve(s.len() as usize) {
+            s.copy_into_slice(dest);
+        }
+        self
+    }
+
+    /// Number of bytes collected so far.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The bytes collected so far.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.buf[..self.len]
+    }
+
+    /// Convert the collected bytes into a `String`, failing if any part did
+    /// not fit.
+    pub fn build(&self, env: &Env) -> Result<String, CapacityExceeded> {
+        if self.overflowed {
+            return Err(CapacityExceeded);
+        }
+        Ok(String::from_bytes(env, self.as_bytes()))
+    }
+
+    /// Claim the next `n` bytes of the buffer, or mark it as overflowed.
+    fn reserve(&mut self, n: usize) -> Option<&mut [u8]> {
+        if self.overflowed || n > N - self.len {
+            self.overflowed = true;
+            return None;
+        }
+        let start = self.len;
+        self.len += n;
+        Some(&mut self.buf[start..self.len])
+    }
+}
+
+impl<const N: usize> Default for StrBuf<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
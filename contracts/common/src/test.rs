@@ -0,0 +1,63 @@
+use soroban_sdk::{Env, String};
+
+use crate::strbuf::{CapacityExceeded, StrBuf};
+
+#[test]
+fn test_concatenates_bytes_and_strings() {
+    let env = Env::default();
+    let mut buf = StrBuf::<64>::new();
+    buf.push_string(&String::from_str(&env, "Hello"))
+        .push_bytes(b", ")
+        .push_string(&String::from_str(&env, "World"))
+        .push_bytes(b"!");
+
+    assert_eq!(buf.as_bytes(), b"Hello, World!");
+    assert_eq!(buf.build(&env), Ok(String::from_str(&env, "Hello, World!")));
+}
+
+#[test]
+fn test_empty_buffer_builds_empty_string() {
+    let env = Env::default();
+    let mut buf = StrBuf::<8>::new();
+    assert!(buf.is_empty());
+
+    buf.push_bytes(b"").push_string(&String::from_str(&env, ""));
+    assert!(buf.is_empty());
+    assert_eq!(buf.build(&env), Ok(String::from_str(&env, "")));
+}
+
+#[test]
+fn test_length_counts_bytes() {
+    let env = Env::default();
+    let mut buf = StrBuf::<16>::new();
+    // "ñ" and "é" are two bytes each in UTF-8.
+    buf.push_string(&String::from_str(&env, "Año"))
+        .push_bytes("é".as_bytes());
+
+    assert_eq!(buf.len(), 6);
+    assert_eq!(buf.build(&env), Ok(String::from_str(&env, "Añoé")));
+}
+
+#[test]
+fn test_fills_exactly_to_capacity() {
+    let env = Env::default();
+    let mut buf = StrBuf::<4>::new();
+    buf.push_bytes(b"ab")
+        .push_string(&String::from_str(&env, "cd"));
+
+    assert_eq!(buf.len(), 4);
+    assert_eq!(buf.build(&env), Ok(String::from_str(&env, "abcd")));
+}
+
+#[test]
+fn test_overflow_fails_instead_of_truncating() {
+    let env = Env::default();
+    let mut buf = StrBuf::<4>::new();
+    buf.push_bytes(b"abc")
+        .push_string(&String::from_str(&env, "de"))
+        .push_bytes(b"f");
+
+    // The part that did not fit and everything after it are dropped.
+    assert_eq!(buf.as_bytes(), b"abc");
+    assert_eq!(buf.build(&env), Err(CapacityExceeded));
+}
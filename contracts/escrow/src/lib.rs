@@ -0,0 +1,262 @@
+#![no_std]
+use soroban_sdk::{
+    contract, contracterror, contractimpl, contracttype, panic_with_error, symbol_short, token,
+    Address, Env, Symbol, Vec,
+};
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum EscrowError {
+    /// The caller is not allowed to perform this action.
+    NotAuthorized = 1,
+    /// The escrow is not in a state that allows this action.
+    InvalidState = 2,
+    /// The buyer cannot reclaim the funds before the deadline.
+    DeadlineNotReached = 3,
+    /// The escrow can no longer be funded or delivered.
+    DeadlinePassed = 4,
+    /// The escrowed amount must be positive.
+    InvalidAmount = 5,
+}
+
+/// Lifecycle of the escrow.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum EscrowState {
+    /// Waiting for the buyer to deposit.
+    Pending,
+    /// The buyer has deposited the amount.
+    Funded,
+    /// The seller has marked the goods as delivered.
+    Delivered,
+    /// The funds were paid to the seller.
+    Released,
+    /// The funds were returned to the buyer.
+    Refunded,
+}
+
+#[contracttype]
+#[derive(Clone)]
+enum DataKey {
+    Buyer,
+    Seller,
+    Arbiter,
+    Token,
+    Amount,
+    Deadline,
+    State,
+}
+
+/// Two-party escrow: the buyer deposits a fixed amount of a SEP-41 token,
+/// which is released to the seller or refunded to the buyer.
+///
+/// The buyer or the arbiter can release, and the seller or the arbiter can
+/// refund. If the seller has not delivered by the deadline, the buyer can
+/// reclaim the funds on their own.
+#[contract]
+pub struct Escrow;
+
+#[contractimpl]
+impl Escrow {
+    /// Constructor - stores the parties, the token and amount held, and the
+    /// ledger timestamp by which the seller must deliver.
+    pub fn __constructor(
+        env: Env,
+        buyer: Address,
+        seller: Address,
+        arbiter: Address,
+        token: Address,
+        amount: i128,
+        deadline: u64,
+    ) {
+        if amount <= 0 {
+            panic_with_error!(&env, EscrowError::InvalidAmount);
+        }
+        let storage = env.storage().instance();
+        storage.set(&DataKey::Buyer, &buyer);
+        storage.set(&DataKey::Seller, &seller);
+        storage.set(&DataKey::Arbiter, &arbiter);
+        storage.set(&DataKey::Token, &token);
+        storage.set(&DataKey::Amount, &amount);
+        storage.set(&DataKey::Deadline, &deadline);
+        storage.set(&DataKey::State, &EscrowState::Pending);
+    }
+
+    /// Transfer the escrowed amount from the buyer into the contract.
+    pub fn deposit(env: Env) -> Result<(), EscrowError> {
+        let buyer = Self::buyer(env.clone());
+        buyer.require_auth();
+        Self::require_state(&env, EscrowState::Pending)?;
+        Self::require_before_deadline(&env)?;
+
+        let amount = Self::amount(env.clone());
+        token::Client::new(&env, &Self::token(env.clone())).transfer(
+            &buyer,
+            &env.current_contract_address(),
+            &amount,
+        );
+        Self::set_state(&env, EscrowState::Funded);
+        env.events()
+            .publish((symbol_short!("funded"), buyer), amount);
+        Ok(())
+    }
+
+    /// Mark the goods as delivered (seller only), which stops the buyer from
+    /// reclaiming the funds after the deadline.
+    pub fn mark_delivered(env: Env) -> Result<(), EscrowError> {
+        let seller = Self::seller(env.clone());
+        seller.require_auth();
+        Self::require_state(&env, EscrowState::Funded)?;
+        Self::require_before_deadline(&env)?;
+
+        Self::set_state(&env, EscrowState::Delivered);
+        env.events()
+            .publish((symbol_short!("delivered"), seller), ());
+        Ok(())
+    }
+
+    /// Pay the escrowed amount to the seller. `caller` must be the buyer or
+    /// the arbiter.
+    pub fn release(env: Env, caller: Address) -> Result<(), EscrowError> {
+        caller.require_auth();
+        if caller != Self::buyer(env.clone()) && caller != Self::arbiter(env.clone()) {
+            return Err(EscrowError::NotAuthorized);
+        }
+        Self::require_open(&env)?;
+
+        Self::payout(&env, &Self::seller(env.clone()));
+        Self::set_state(&env, EscrowState::Released);
+        env.events().publish(
+            (symbol_short!("released"), caller),
+            Self::amount(env.clone()),
+        );
+        Ok(())
+    }
+
+    /// Return the escrowed amount to the buyer. `caller` must be the seller
+    /// or the arbiter, or the buyer once the deadline has passed without
+    /// delivery.
+    pub fn refund(env: Env, caller: Address) -> Result<(), EscrowError> {
+        caller.require_auth();
+        let state = Self::require_open(&env)?;
+
+        let buyer = Self::buyer(env.clone());
+        if caller == buyer {
+            if state == EscrowState::Delivered {
+                return Err(EscrowError::InvalidState);
+            }
+            if env.ledger().timestamp() <= Self::deadline(env.clone()) {
+                return Err(EscrowError::DeadlineNotReached);
+            }
+        } else if caller != Self::seller(env.clone()) && caller != Self::arbiter(env.clone()) {
+            return Err(EscrowError::NotAuthorized);
+        }
+
+        Self::payout(&env, &buyer);
+        Self::set_state(&env, EscrowState::Refunded);
+        env.events().publish(
+            (symbol_short!("refunded"), caller),
+            Self::amount(env.clone()),
+        );
+        Ok(())
+    }
+
+    /// Get the current state of the escrow.
+    pub fn state(env: Env) -> EscrowState {
+        env.storage()
+            .instance()
+            .get(&DataKey::State)
+            .unwrap_or(EscrowState::Pending)
+    }
+
+    /// Get the buyer.
+    pub fn buyer(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Buyer)
+            .unwrap_or_else(|| panic!("Buyer not set"))
+    }
+
+    /// Get the seller.
+    pub fn seller(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Seller)
+            .unwrap_or_else(|| panic!("Seller not set"))
+    }
+
+    /// Get the arbiter.
+    pub fn arbiter(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Arbiter)
+            .unwrap_or_else(|| panic!("Arbiter not set"))
+    }
+
+    /// Get the token held in escrow.
+    pub fn token(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Token)
+            .unwrap_or_else(|| panic!("Token not set"))
+    }
+
+    /// Get the amount held in escrow.
+    pub fn amount(env: Env) -> i128 {
+        env.storage().instance().get(&DataKey::Amount).unwrap_or(0)
+    }
+
+    /// Get the ledger timestamp by which the seller must deliver.
+    pub fn deadline(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::Deadline)
+            .unwrap_or(0)
+    }
+
+    /// Returns the standard interfaces this contract implements.
+    pub fn supported_interfaces(env: Env) -> Vec<Symbol> {
+        Vec::new(&env)
+    }
+}
+
+impl Escrow {
+    fn set_state(env: &Env, state: EscrowState) {
+        env.storage().instance().set(&DataKey::State, &state);
+    }
+
+    fn require_state(env: &Env, state: EscrowState) -> Result<(), EscrowError> {
+        if Self::state(env.clone()) != state {
+            return Err(EscrowError::InvalidState);
+        }
+        Ok(())
+    }
+
+    /// Fail unless the funds are deposited and not yet paid out, returning
+    /// the current state.
+    fn require_open(env: &Env) -> Result<EscrowState, EscrowError> {
+        match Self::state(env.clone()) {
+            state @ (EscrowState::Funded | EscrowState::Delivered) => Ok(state),
+            _ => Err(EscrowError::InvalidState),
+        }
+    }
+
+    fn require_before_deadline(env: &Env) -> Result<(), EscrowError> {
+        if env.ledger().timestamp() > Self::deadline(env.clone()) {
+            return Err(EscrowError::DeadlinePassed);
+        }
+        Ok(())
+    }
+
+    fn payout(env: &Env, to: &Address) {
+        token::Client::new(env, &Self::token(env.clone())).transfer(
+            &env.current_contract_address(),
+            to,
+            &Self::amount(env.clone()),
+        );
+    }
+}
+
+#[cfg(test)]
+mod test;
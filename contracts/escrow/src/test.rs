@@ -0,0 +1,276 @@
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events, Ledger},
+    token::{StellarAssetClient, TokenClient},
+    vec, Address, Env, IntoVal, Val, Vec,
+};
+
+use crate::{Escrow, EscrowClient, EscrowError, EscrowState};
+
+const AMOUNT: i128 = 1000;
+const DEADLINE: u64 = 10_000;
+
+struct Setup<'a> {
+    buyer: Address,
+    seller: Address,
+    arbiter: Address,
+    tokens: TokenClient<'a>,
+    client: EscrowClient<'a>,
+}
+
+fn setup(env: &Env) -> Setup<'_> {
+    env.mock_all_auths();
+    let issuer = Address::generate(env);
+    let sac = env.register_stellar_asset_contract_v2(issuer);
+    let token = StellarAssetClient::new(env, &sac.address());
+
+    let buyer = Address::generate(env);
+    let seller = Address::generate(env);
+    let arbiter = Address::generate(env);
+    token.mint(&buyer, &AMOUNT);
+
+    let contract_id = env.register(
+        Escrow,
+        (&buyer, &seller, &arbiter, &sac.address(), AMOUNT, DEADLINE),
+    );
+
+    Setup {
+        buyer,
+        seller,
+        arbiter,
+        tokens: TokenClient::new(env, &sac.address()),
+        client: EscrowClient::new(env, &contract_id),
+    }
+}
+
+fn funded(env: &Env) -> Setup<'_> {
+    let s = setup(env);
+    s.client.deposit();
+    s
+}
+
+/// The escrow event, without the token transfer event emitted before it.
+fn last_event(env: &Env) -> Vec<(Address, Vec<Val>, Val)> {
+    vec![env, env.events().all().last().unwrap()]
+}
+
+#[test]
+fn test_config() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    assert_eq!(s.client.buyer(), s.buyer);
+    assert_eq!(s.client.seller(), s.seller);
+    assert_eq!(s.client.arbiter(), s.arbiter);
+    assert_eq!(s.client.token(), s.tokens.address);
+    assert_eq!(s.client.amount(), AMOUNT);
+    assert_eq!(s.client.deadline(), DEADLINE);
+    assert_eq!(s.client.state(), EscrowState::Pending);
+}
+
+#[test]
+#[should_panic]
+fn test_constructor_rejects_zero_amount() {
+    let env = Env::default();
+    let token = Address::generate(&env);
+    env.register(
+        Escrow,
+        (
+            Address::generate(&env),
+            Address::generate(&env),
+            Address::generate(&env),
+            token,
+            0_i128,
+            DEADLINE,
+        ),
+    );
+}
+
+#[test]
+fn test_deposit() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.client.deposit();
+    assert_eq!(
+        last_event(&env),
+        vec![
+            &env,
+            (
+                s.client.address.clone(),
+                (symbol_short!("funded"), s.buyer.clone()).into_val(&env),
+                AMOUNT.into_val(&env),
+            ),
+        ]
+    );
+    assert_eq!(s.client.state(), EscrowState::Funded);
+    assert_eq!(s.tokens.balance(&s.buyer), 0);
+    assert_eq!(s.tokens.balance(&s.client.address), AMOUNT);
+
+    assert_eq!(s.client.try_deposit(), Err(Ok(EscrowError::InvalidState)));
+}
+
+#[test]
+fn test_deposit_after_deadline() {
+    let env = Env::default();
+    let s = setup(&env);
+    env.ledger().set_timestamp(DEADLINE + 1);
+
+    assert_eq!(s.client.try_deposit(), Err(Ok(EscrowError::DeadlinePassed)));
+}
+
+#[test]
+fn test_buyer_releases_to_seller() {
+    let env = Env::default();
+    let s = funded(&env);
+
+    s.client.mark_delivered();
+    assert_eq!(s.client.state(), EscrowState::Delivered);
+
+    s.client.release(&s.buyer);
+    assert_eq!(
+        last_event(&env),
+        vec![
+            &env,
+            (
+                s.client.address.clone(),
+                (symbol_short!("released"), s.buyer.clone()).into_val(&env),
+                AMOUNT.into_val(&env),
+            ),
+        ]
+    );
+    assert_eq!(s.client.state(), EscrowState::Released);
+    assert_eq!(s.tokens.balance(&s.seller), AMOUNT);
+    assert_eq!(s.tokens.balance(&s.client.address), 0);
+}
+
+#[test]
+fn test_arbiter_releases_to_seller() {
+    let env = Env::default();
+    let s = funded(&env);
+
+    s.client.release(&s.arbiter);
+    assert_eq!(s.tokens.balance(&s.seller), AMOUNT);
+}
+
+#[test]
+fn test_seller_cannot_release() {
+    let env = Env::default();
+    let s = funded(&env);
+
+    assert_eq!(
+        s.client.try_release(&s.seller),
+        Err(Ok(EscrowError::NotAuthorized))
+    );
+    assert_eq!(s.client.state(), EscrowState::Funded);
+}
+
+#[test]
+fn test_release_before_deposit() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    assert_eq!(
+        s.client.try_release(&s.buyer),
+        Err(Ok(EscrowError::InvalidState))
+    );
+}
+
+#[test]
+fn test_seller_refunds_buyer() {
+    let env = Env::default();
+    let s = funded(&env);
+
+    s.client.refund(&s.seller);
+    assert_eq!(
+        last_event(&env),
+        vec![
+            &env,
+            (
+                s.client.address.clone(),
+                (symbol_short!("refunded"), s.seller.clone()).into_val(&env),
+                AMOUNT.into_val(&env),
+            ),
+        ]
+    );
+    assert_eq!(s.client.state(), EscrowState::Refunded);
+    assert_eq!(s.tokens.balance(&s.buyer), AMOUNT);
+
+    // Funds can only be paid out once.
+    assert_eq!(
+        s.client.try_release(&s.arbiter),
+        Err(Ok(EscrowError::InvalidState))
+    );
+}
+
+#[test]
+fn test_arbiter_refunds_after_delivery() {
+    let env = Env::default();
+    let s = funded(&env);
+    s.client.mark_delivered();
+
+    s.client.refund(&s.arbiter);
+    assert_eq!(s.tokens.balance(&s.buyer), AMOUNT);
+}
+
+#[test]
+fn test_buyer_refund_waits_for_deadline() {
+    let env = Env::default();
+    let s = funded(&env);
+
+    assert_eq!(
+        s.client.try_refund(&s.buyer),
+        Err(Ok(EscrowError::DeadlineNotReached))
+    );
+
+    env.ledger().set_timestamp(DEADLINE + 1);
+    s.client.refund(&s.buyer);
+    assert_eq!(s.client.state(), EscrowState::Refunded);
+    assert_eq!(s.tokens.balance(&s.buyer), AMOUNT);
+}
+
+#[test]
+fn test_buyer_cannot_refund_after_delivery() {
+    let env = Env::default();
+    let s = funded(&env);
+    s.client.mark_delivered();
+    env.ledger().set_timestamp(DEADLINE + 1);
+
+    assert_eq!(
+        s.client.try_refund(&s.buyer),
+        Err(Ok(EscrowError::InvalidState))
+    );
+}
+
+#[test]
+fn test_deliver_after_deadline() {
+    let env = Env::default();
+    let s = funded(&env);
+    env.ledger().set_timestamp(DEADLINE + 1);
+
+    assert_eq!(
+        s.client.try_mark_delivered(),
+        Err(Ok(EscrowError::DeadlinePassed))
+    );
+}
+
+#[test]
+fn test_outsider_cannot_refund() {
+    let env = Env::default();
+    let s = funded(&env);
+
+    assert_eq!(
+        s.client.try_refund(&Address::generate(&env)),
+        Err(Ok(EscrowError::NotAuthorized))
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_deposit_requires_buyer_auth() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    env.set_auths(&[]);
+    s.client.deposit();
+}
@@ -1,25 +1,370 @@
 #![no_std]
-use soroban_sdk::{contract, contractimpl, symbol_short, vec, Env, Symbol, Vec};
+use common::strbuf::StrBuf;
+use soroban_sdk::{
+    contract, contracterror, contractimpl, contracttype, symbol_short, token, Address, Env,
+    IntoVal, Map, String, Symbol, TryFromVal, Val, Vec,
+};
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum HelloError {
+    /// No translation is registered for the requested language.
+    LanguageNotFound = 1,
+    /// The caller holds less than the premium minimum balance.
+    InsufficientBalance = 2,
+    /// No greeting with the requested id is on the board.
+    GreetingNotFound = 3,
+    /// A posted greeting must be between 1 and `MAX_GREETING_LEN` bytes.
+    InvalidGreetingLength = 4,
+}
+
+/// Longest greeting `hello` can compose, in bytes.
+const MAX_GREETING_LEN: usize = 256;
+
+/// Most greetings the public board holds at once, so its index stays small.
+/// Posting to a full board evicts the oldest greeting.
+const MAX_POSTS: u32 = 100;
+
+const DAY_IN_LEDGERS: u32 = 17280;
+/// Persistent entries whose TTL falls below this many ledgers are extended.
+const BUMP_THRESHOLD: u32 = 7 * DAY_IN_LEDGERS;
+/// Number of ledgers persistent entries are extended to live for.
+const BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+
+/// Semantic version of the deployed contract.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct Version {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+}
+
+/// A version together with the ledger time it was set.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VersionRecord {
+    pub version: Version,
+    pub timestamp: u64,
+}
+
+/// A greeting posted to the public board.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Post {
+    pub id: u32,
+    pub author: Address,
+    pub text: String,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone)]
+enum DataKey {
+    /// Address allowed to manage translations.
+    Admin,
+    /// Greeting template for each language.
+    Translations,
+    /// Custom greeting set by an address.
+    Greeting(Address),
+    /// Number of greetings sent through `hello_from`.
+    TotalGreetings,
+    /// Every version bumped to, oldest first.
+    VersionHistory,
+    /// Token that must be held to receive a premium greeting.
+    PremiumToken,
+    /// Balance of the premium token required for a premium greeting.
+    PremiumMinBalance,
+    /// Greeting on the public board.
+    Post(u32),
+    /// Ids of the greetings on the board, oldest first, in persistent
+    /// storage so a busy board does not grow the contract instance.
+    PostIds,
+    /// Id assigned to the next posted greeting.
+    NextPostId,
+    /// Number of greetings sent by an address.
+    GreetingsBy(Address),
+}
 
 #[contract]
 pub struct HelloWorld;
 
 #[contractimpl]
 impl HelloWorld {
-    /// Returns a simple greeting message
-    pub fn hello(env: Env, to: Symbol) -> Vec<Symbol> {
-        vec![&env, symbol_short!("Hello"), to]
+    /// Constructor - stores the admin address
+    pub fn __constructor(env: Env, admin: Address) {
+        env.storage().instance().set(&DataKey::Admin, &admin);
+    }
+
+    /// Returns the admin address
+    pub fn get_admin(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic!("Admin not set"))
+    }
+
+    /// Returns a simple greeting message, e.g. "Hello, World!"
+    pub fn hello(env: Env, to: String) -> String {
+        compose(&env, &String::from_str(&env, "Hello"), &to, b"!")
+    }
+
+    /// Greets `to` on behalf of `caller`, counting the greeting and emitting
+    /// a `greeted` event with the name
+    pub fn hello_from(env: Env, caller: Address, to: String) -> String {
+        caller.require_auth();
+
+        let total = Self::total_greetings(env.clone()) + 1;
+        env.storage()
+            .instance()
+            .set(&DataKey::TotalGreetings, &total);
+        let by = Self::greetings_by(env.clone(), caller.clone()) + 1;
+        write_persistent(&env, &DataKey::GreetingsBy(caller.clone()), &by);
+
+        env.events()
+            .publish((symbol_short!("greeted"), caller), to.clone());
+        Self::hello(env, to)
+    }
+
+    /// Returns the number of greetings sent through `hello_from`
+    pub fn total_greetings(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::TotalGreetings)
+            .unwrap_or(0)
+    }
+
+    /// Returns the number of greetings sent by `addr`
+    pub fn greetings_by(env: Env, addr: Address) -> u64 {
+        read_persistent(&env, &DataKey::GreetingsBy(addr)).unwrap_or(0)
+    }
+
+    /// Sets the greeting used by `hello_in` for `lang`, e.g. "Hola" (admin only)
+    pub fn set_translation(env: Env, lang: Symbol, template: String) {
+        Self::get_admin(env.clone()).require_auth();
+        let mut translations = Self::translations(&env);
+        translations.set(lang, template);
+        env.storage()
+            .instance()
+            .set(&DataKey::Translations, &translations);
+    }
+
+    /// Returns a greeting for `name` in `lang`, e.g. "Hola, Ana!"
+    pub fn hello_in(env: Env, lang: Symbol, name: String) -> Result<String, HelloError> {
+        let template = Self::translations(&env)
+            .get(lang)
+            .ok_or(HelloError::LanguageNotFound)?;
+        Ok(compose(&env, &template, &name, b"!"))
+    }
+
+    /// Requires holding at least `min_balance` of `token` to receive a
+    /// premium greeting (admin only)
+    pub fn set_premium(env: Env, token: Address, min_balance: i128) {
+        Self::get_admin(env.clone()).require_auth();
+        if min_balance < 0 {
+            panic!("Minimum balance must not be negative");
+        }
+        let storage = env.storage().instance();
+        storage.set(&DataKey::PremiumToken, &token);
+        storage.set(&DataKey::PremiumMinBalance, &min_balance);
+    }
+
+    /// Returns the token checked by `premium_hello`
+    pub fn premium_token(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::PremiumToken)
+            .unwrap_or_else(|| panic!("Premium token not set"))
+    }
+
+    /// Returns the premium token balance required by `premium_hello`
+    pub fn premium_min_balance(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::PremiumMinBalance)
+            .unwrap_or(0)
+    }
+
+    /// Returns an extended greeting for `name` if `caller` holds at least the
+    /// premium minimum balance of the premium token
+    pub fn premium_hello(env: Env, caller: Address, name: String) -> Result<String, HelloError> {
+        caller.require_auth();
+
+        let balance = token::Client::new(&env, &Self::premium_token(env.clone())).balance(&caller);
+        if balance < Self::premium_min_balance(env.clone()) {
+            return Err(HelloError::InsufficientBalance);
+        }
+        Ok(compose(
+            &env,
+            &String::from_str(&env, "Hello"),
+            &name,
+            b"! Thanks for being a premium member.",
+        ))
+    }
+
+    /// Posts `text` to the public greeting board, returning its id. The
+    /// board holds at most `MAX_POSTS` greetings, dropping the oldest one to
+    /// make room for a new post
+    pub fn post_greeting(env: Env, author: Address, text: String) -> Result<u32, HelloError> {
+        author.require_auth();
+        if text.is_empty() || text.len() as usize > MAX_GREETING_LEN {
+            return Err(HelloError::InvalidGreetingLength);
+        }
+        let mut ids = Self::post_ids(&env);
+        if ids.len() >= MAX_POSTS {
+            let oldest = ids.pop_front_unchecked();
+            env.storage().persistent().remove(&DataKey::Post(oldest));
+        }
+
+        let id: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::NextPostId)
+            .unwrap_or(0);
+        let post = Post {
+            id,
+            author,
+            text,
+            timestamp: env.ledger().timestamp(),
+        };
+        write_persistent(&env, &DataKey::Post(id), &post);
+
+        ids.push_back(id);
+        write_persistent(&env, &DataKey::PostIds, &ids);
+        env.storage()
+            .instance()
+            .set(&DataKey::NextPostId, &(id + 1));
+        Ok(id)
+    }
+
+    /// Returns up to `limit` greetings from the board, oldest first,
+    /// skipping the first `start`
+    pub fn list_greetings(env: Env, start: u32, limit: u32) -> Vec<Post> {
+        let ids = Self::post_ids(&env);
+        let end = ids.len().min(start.saturating_add(limit));
+
+        let mut posts = Vec::new(&env);
+        for i in start..end {
+            let id = ids.get_unchecked(i);
+            if let Some(post) = read_persistent(&env, &DataKey::Post(id)) {
+                posts.push_back(post);
+            }
+        }
+        posts
+    }
+
+    /// Removes greeting `id` from the board (admin only)
+    pub fn remove_greeting(env: Env, id: u32) -> Result<(), HelloError> {
+        Self::get_admin(env.clone()).require_auth();
+
+        let mut ids = Self::post_ids(&env);
+        let index = ids.first_index_of(id).ok_or(HelloError::GreetingNotFound)?;
+        ids.remove(index);
+        write_persistent(&env, &DataKey::PostIds, &ids);
+        env.storage().persistent().remove(&DataKey::Post(id));
+        Ok(())
+    }
+
+    /// Returns a greeting with a custom message, e.g. "Greetings, World!"
+    pub fn greet(env: Env, name: String) -> String {
+        compose(&env, &String::from_str(&env, "Greetings"), &name, b"!")
+    }
+
+    /// Sets the custom greeting for `caller`
+    pub fn set_greeting(env: Env, caller: Address, greeting: String) {
+        caller.require_auth();
+        write_persistent(&env, &DataKey::Greeting(caller), &greeting);
+    }
+
+    /// Returns the custom greeting set by `addr`, if any
+    pub fn get_greeting(env: Env, addr: Address) -> Option<String> {
+        read_persistent(&env, &DataKey::Greeting(addr))
+    }
+
+    /// Returns the current version, 1.0.0 until the first bump
+    pub fn version_info(env: Env) -> Version {
+        Self::version_history(env)
+            .last()
+            .map(|record| record.version)
+            .unwrap_or(Version {
+                major: 1,
+                minor: 0,
+                patch: 0,
+            })
+    }
+
+    /// Moves to a newer `version`, recording the current ledger timestamp (admin only)
+    pub fn bump_version(env: Env, version: Version) {
+        Self::get_admin(env.clone()).require_auth();
+        if version <= Self::version_info(env.clone()) {
+            panic!("Version must increase");
+        }
+
+        let mut history = Self::version_history(env.clone());
+        history.push_back(VersionRecord {
+            version,
+            timestamp: env.ledger().timestamp(),
+        });
+        env.storage()
+            .instance()
+            .set(&DataKey::VersionHistory, &history);
+    }
+
+    /// Returns every version bumped to, oldest first
+    pub fn version_history(env: Env) -> Vec<VersionRecord> {
+        env.storage()
+            .instance()
+            .get(&DataKey::VersionHistory)
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Returns the standard interfaces this contract implements
+    pub fn supported_interfaces(env: Env) -> Vec<Symbol> {
+        Vec::new(&env)
+    }
+}
+
+impl HelloWorld {
+    fn translations(env: &Env) -> Map<Symbol, String> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Translations)
+            .unwrap_or(Map::new(env))
     }
 
-    /// Returns a greeting with a custom message
-    pub fn greet(_env: Env, _name: Symbol) -> Symbol {
-        symbol_short!("Hello")
+    fn post_ids(env: &Env) -> Vec<u32> {
+        read_persistent(env, &DataKey::PostIds).unwrap_or(Vec::new(env))
     }
+}
 
-    /// Returns version information
-    pub fn version() -> u32 {
-        1
+/// Read a persistent entry, extending its TTL if it exists.
+fn read_persistent<V: TryFromVal<Env, Val>>(env: &Env, key: &DataKey) -> Option<V> {
+    let storage = env.storage().persistent();
+    let value = storage.get(key);
+    if value.is_some() {
+        storage.extend_ttl(key, BUMP_THRESHOLD, BUMP_AMOUNT);
     }
+    value
+}
+
+/// Write a persistent entry and extend its TTL.
+fn write_persistent<V: IntoVal<Env, Val>>(env: &Env, key: &DataKey, value: &V) {
+    let storage = env.storage().persistent();
+    storage.set(key, value);
+    storage.extend_ttl(key, BUMP_THRESHOLD, BUMP_AMOUNT);
+}
+
+/// Build "<greeting>, <name><suffix>", panicking if it is longer than
+/// `MAX_GREETING_LEN` bytes.
+fn compose(env: &Env, greeting: &String, name: &String, suffix: &[u8]) -> String {
+    let mut buf = StrBuf::<MAX_GREETING_LEN>::new();
+    buf.push_string(greeting)
+        .push_bytes(b", ")
+        .push_string(name)
+        .push_bytes(suffix);
+    buf.build(env)
+        .unwrap_or_else(|_| panic!("Greeting too long"))
 }
 
 #[cfg(test)]
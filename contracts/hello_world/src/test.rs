@@ -1,36 +1,476 @@
-use soroban_sdk::{symbol_short, vec, Env, testutils::Address as _};
+extern crate std;
 
-use crate::{HelloWorld, HelloWorldClient};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{
+        storage::Persistent as _, Address as _, AuthorizedFunction, AuthorizedInvocation, Events,
+        Ledger,
+    },
+    token::StellarAssetClient,
+    vec, Address, Env, IntoVal, String, Symbol,
+};
+
+use crate::{
+    DataKey, HelloError, HelloWorld, HelloWorldClient, Post, Version, VersionRecord, BUMP_AMOUNT,
+    MAX_GREETING_LEN, MAX_POSTS,
+};
+
+fn setup(env: &Env) -> (Address, HelloWorldClient<'_>) {
+    let admin = Address::generate(env);
+    let contract_id = env.register(HelloWorld, (&admin,));
+    (admin, HelloWorldClient::new(env, &contract_id))
+}
 
 #[test]
 fn test() {
     let env = Env::default();
-    let contract_id = env.register_contract(None, HelloWorld);
-    let client = HelloWorldClient::new(&env, &contract_id);
+    let (_, client) = setup(&env);
 
-    let words = client.hello(&symbol_short!("Dev"));
+    let greeting = client.hello(&String::from_str(&env, "Dev"));
+    assert_eq!(greeting, String::from_str(&env, "Hello, Dev!"));
+}
+
+#[test]
+fn test_hello_long_name_with_spaces() {
+    let env = Env::default();
+    let (_, client) = setup(&env);
+
+    let greeting = client.hello(&String::from_str(&env, "Stellar Developer"));
     assert_eq!(
-        words,
-        vec![&env, symbol_short!("Hello"), symbol_short!("Dev")]
+        greeting,
+        String::from_str(&env, "Hello, Stellar Developer!")
     );
 }
 
+#[test]
+#[should_panic(expected = "Greeting too long")]
+fn test_hello_name_too_long() {
+    let env = Env::default();
+    let (_, client) = setup(&env);
+
+    client.hello(&String::from_bytes(&env, &[b'a'; 256]));
+}
+
+#[test]
+fn test_hello_fills_max_greeting_len() {
+    let env = Env::default();
+    let (_, client) = setup(&env);
+
+    // "Hello, " and "!" around the name add 8 bytes.
+    let name = String::from_bytes(&env, &[b'a'; MAX_GREETING_LEN - 8]);
+    assert_eq!(client.hello(&name).len() as usize, MAX_GREETING_LEN);
+}
+
 #[test]
 fn test_greet() {
     let env = Env::default();
-    let contract_id = env.register_contract(None, HelloWorld);
-    let client = HelloWorldClient::new(&env, &contract_id);
+    let (_, client) = setup(&env);
 
-    let greeting = client.greet(&symbol_short!("World"));
-    assert_eq!(greeting, symbol_short!("Hello"));
+    let greeting = client.greet(&String::from_str(&env, "World"));
+    assert_eq!(greeting, String::from_str(&env, "Greetings, World!"));
+}
+
+#[test]
+fn test_set_greeting() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let greeting = String::from_str(&env, "Good morning");
+
+    assert_eq!(client.get_greeting(&alice), None);
+    client.set_greeting(&alice, &greeting);
+    assert_eq!(
+        env.auths(),
+        std::vec![(
+            alice.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    client.address.clone(),
+                    Symbol::new(&env, "set_greeting"),
+                    (alice.clone(), greeting.clone()).into_val(&env),
+                )),
+                sub_invocations: std::vec![],
+            }
+        )]
+    );
+    assert_eq!(client.get_greeting(&alice), Some(greeting));
+    assert_eq!(client.get_greeting(&bob), None);
+}
+
+#[test]
+#[should_panic]
+fn test_set_greeting_requires_auth() {
+    let env = Env::default();
+    let (_, client) = setup(&env);
+
+    client.set_greeting(&Address::generate(&env), &String::from_str(&env, "Hi"));
 }
 
 #[test]
 fn test_version() {
     let env = Env::default();
-    let contract_id = env.register_contract(None, HelloWorld);
-    let client = HelloWorldClient::new(&env, &contract_id);
+    let (_, client) = setup(&env);
+
+    assert_eq!(
+        client.version_info(),
+        Version {
+            major: 1,
+            minor: 0,
+            patch: 0,
+        }
+    );
+    assert!(client.version_history().is_empty());
+}
+
+#[test]
+fn test_bump_version() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let minor = Version {
+        major: 1,
+        minor: 1,
+        patch: 0,
+    };
+    let major = Version {
+        major: 2,
+        minor: 0,
+        patch: 0,
+    };
+
+    env.ledger().set_timestamp(1000);
+    client.bump_version(&minor);
+    env.ledger().set_timestamp(2000);
+    client.bump_version(&major);
+
+    assert_eq!(client.version_info(), major);
+    assert_eq!(
+        client.version_history(),
+        vec![
+            &env,
+            VersionRecord {
+                version: minor,
+                timestamp: 1000,
+            },
+            VersionRecord {
+                version: major,
+                timestamp: 2000,
+            },
+        ]
+    );
+}
+
+#[test]
+#[should_panic(expected = "Version must increase")]
+fn test_bump_version_must_increase() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+
+    client.bump_version(&Version {
+        major: 1,
+        minor: 0,
+        patch: 0,
+    });
+}
+
+#[test]
+#[should_panic]
+fn test_bump_version_requires_admin() {
+    let env = Env::default();
+    let (_, client) = setup(&env);
+
+    client.bump_version(&Version {
+        major: 2,
+        minor: 0,
+        patch: 0,
+    });
+}
+
+#[test]
+fn test_supported_interfaces() {
+    let env = Env::default();
+    let (_, client) = setup(&env);
+
+    assert!(client.supported_interfaces().is_empty());
+}
+
+#[test]
+fn test_get_admin() {
+    let env = Env::default();
+    let (admin, client) = setup(&env);
+
+    assert_eq!(client.get_admin(), admin);
+}
+
+#[test]
+fn test_hello_in() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let name = String::from_str(&env, "Ana");
+
+    assert_eq!(
+        client.try_hello_in(&symbol_short!("es"), &name),
+        Err(Ok(HelloError::LanguageNotFound))
+    );
+
+    client.set_translation(&symbol_short!("es"), &String::from_str(&env, "Hola"));
+    client.set_translation(&symbol_short!("fr"), &String::from_str(&env, "Bonjour"));
+    assert_eq!(
+        client.hello_in(&symbol_short!("es"), &name),
+        String::from_str(&env, "Hola, Ana!")
+    );
+    assert_eq!(
+        client.hello_in(&symbol_short!("fr"), &name),
+        String::from_str(&env, "Bonjour, Ana!")
+    );
+
+    // Setting a language again replaces its template.
+    client.set_translation(&symbol_short!("es"), &String::from_str(&env, "Buenas"));
+    assert_eq!(
+        client.hello_in(&symbol_short!("es"), &name),
+        String::from_str(&env, "Buenas, Ana!")
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_set_translation_requires_admin() {
+    let env = Env::default();
+    let (_, client) = setup(&env);
+
+    client.set_translation(&symbol_short!("es"), &String::from_str(&env, "Hola"));
+}
+
+#[test]
+fn test_hello_from_counts_greetings() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let name = String::from_str(&env, "Dev");
+
+    assert_eq!(client.total_greetings(), 0);
+    assert_eq!(
+        client.hello_from(&alice, &name),
+        String::from_str(&env, "Hello, Dev!")
+    );
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (symbol_short!("greeted"), alice.clone()).into_val(&env),
+                name.into_val(&env),
+            ),
+        ]
+    );
+    client.hello_from(&alice, &name);
+    client.hello_from(&bob, &name);
+
+    assert_eq!(client.total_greetings(), 3);
+    assert_eq!(client.greetings_by(&alice), 2);
+    assert_eq!(client.greetings_by(&bob), 1);
+}
+
+#[test]
+#[should_panic]
+fn test_hello_from_requires_auth() {
+    let env = Env::default();
+    let (_, client) = setup(&env);
+
+    client.hello_from(&Address::generate(&env), &String::from_str(&env, "Dev"));
+}
+
+#[test]
+fn test_premium_hello() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let sac = env.register_stellar_asset_contract_v2(Address::generate(&env));
+    let token = StellarAssetClient::new(&env, &sac.address());
+    client.set_premium(&sac.address(), &100);
+    assert_eq!(client.premium_token(), sac.address());
+    assert_eq!(client.premium_min_balance(), 100);
+
+    let caller = Address::generate(&env);
+    let name = String::from_str(&env, "Ana");
+    token.mint(&caller, &99);
+    assert_eq!(
+        client.try_premium_hello(&caller, &name),
+        Err(Ok(HelloError::InsufficientBalance))
+    );
+
+    token.mint(&caller, &1);
+    assert_eq!(
+        client.premium_hello(&caller, &name),
+        String::from_str(&env, "Hello, Ana! Thanks for being a premium member.")
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_set_premium_requires_admin() {
+    let env = Env::default();
+    let (_, client) = setup(&env);
+
+    client.set_premium(&Address::generate(&env), &100);
+}
+
+#[test]
+fn test_greeting_board() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    env.ledger().set_timestamp(1000);
+
+    assert_eq!(
+        client.post_greeting(&alice, &String::from_str(&env, "gm")),
+        0
+    );
+    assert_eq!(
+        client.post_greeting(&bob, &String::from_str(&env, "hi all")),
+        1
+    );
+    assert_eq!(
+        client.post_greeting(&alice, &String::from_str(&env, "gn")),
+        2
+    );
+
+    assert_eq!(client.list_greetings(&0, &10).len(), 3);
+    assert_eq!(
+        client.list_greetings(&1, &1),
+        vec![
+            &env,
+            Post {
+                id: 1,
+                author: bob,
+                text: String::from_str(&env, "hi all"),
+                timestamp: 1000,
+            },
+        ]
+    );
+    assert!(client.list_greetings(&3, &10).is_empty());
+}
+
+#[test]
+fn test_remove_greeting() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let alice = Address::generate(&env);
+    client.post_greeting(&alice, &String::from_str(&env, "gm"));
+    client.post_greeting(&alice, &String::from_str(&env, "spam"));
+    client.post_greeting(&alice, &String::from_str(&env, "gn"));
+
+    client.remove_greeting(&1);
+    let posts = client.list_greetings(&0, &10);
+    assert_eq!(posts.len(), 2);
+    assert_eq!(posts.get_unchecked(0).id, 0);
+    assert_eq!(posts.get_unchecked(1).id, 2);
+
+    assert_eq!(
+        client.try_remove_greeting(&1),
+        Err(Ok(HelloError::GreetingNotFound))
+    );
+    // Ids are never reused after a removal.
+    assert_eq!(
+        client.post_greeting(&alice, &String::from_str(&env, "gm")),
+        3
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_remove_greeting_requires_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    client.post_greeting(&Address::generate(&env), &String::from_str(&env, "gm"));
+
+    env.set_auths(&[]);
+    client.remove_greeting(&0);
+}
+
+#[test]
+fn test_post_invalid_greeting_length() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let author = Address::generate(&env);
+    let too_long = String::from_bytes(&env, &[b'a'; MAX_GREETING_LEN + 1]);
+
+    for text in [String::from_str(&env, ""), too_long] {
+        assert_eq!(
+            client.try_post_greeting(&author, &text),
+            Err(Ok(HelloError::InvalidGreetingLength))
+        );
+    }
+    assert!(client.list_greetings(&0, &10).is_empty());
+}
+
+#[test]
+fn test_full_board_evicts_oldest_greeting() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let text = String::from_str(&env, "gm");
+    for _ in 0..MAX_POSTS {
+        client.post_greeting(&alice, &text);
+    }
+
+    // A full board still accepts posts from anyone, dropping the oldest.
+    assert_eq!(client.post_greeting(&bob, &text), MAX_POSTS);
+    let posts = client.list_greetings(&0, &(MAX_POSTS + 1));
+    assert_eq!(posts.len(), MAX_POSTS);
+    assert_eq!(posts.first_unchecked().id, 1);
+    assert_eq!(posts.last_unchecked().author, bob);
+    assert_eq!(
+        client.try_remove_greeting(&0),
+        Err(Ok(HelloError::GreetingNotFound))
+    );
+    let evicted = env.as_contract(&client.address, || {
+        env.storage().persistent().has(&DataKey::Post(0))
+    });
+    assert!(!evicted);
+}
+
+#[test]
+fn test_greeting_board_ttl_extended() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    client.post_greeting(&Address::generate(&env), &String::from_str(&env, "gm"));
+
+    for key in [DataKey::Post(0), DataKey::PostIds] {
+        let ttl = env.as_contract(&client.address, || env.storage().persistent().get_ttl(&key));
+        assert_eq!(ttl, BUMP_AMOUNT);
+    }
+}
+
+#[test]
+fn test_greeting_entries_ttl_extended() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let alice = Address::generate(&env);
+    client.set_greeting(&alice, &String::from_str(&env, "gm"));
+    client.hello_from(&alice, &String::from_str(&env, "Bob"));
 
-    let version = client.version();
-    assert_eq!(version, 1);
+    for key in [
+        DataKey::Greeting(alice.clone()),
+        DataKey::GreetingsBy(alice),
+    ] {
+        let ttl = env.as_contract(&client.address, || env.storage().persistent().get_ttl(&key));
+        assert_eq!(ttl, BUMP_AMOUNT);
+    }
 }
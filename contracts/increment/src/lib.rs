@@ -1,63 +1,771 @@
 #![no_std]
-use soroban_sdk::{contract, contractimpl, symbol_short, Env};
+use soroban_sdk::{
+    contract, contracterror, contractimpl, contracttype, panic_with_error, symbol_short, token,
+    vec, Address, Env, IntoVal, Map, Symbol, TryFromVal, Val, Vec,
+};
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum CounterError {
+    /// The counter would exceed `u32::MAX`.
+    Overflow = 1,
+    /// The step size must be greater than zero.
+    InvalidStep = 2,
+    /// The counter would exceed the configured maximum.
+    LimitReached = 3,
+    /// The caller is not the contract admin.
+    NotAdmin = 4,
+    /// Counter updates are paused.
+    ContractPaused = 5,
+    /// The caller holds less than the required token balance.
+    InsufficientBalance = 6,
+    /// The maximum number of named counters already exists.
+    TooManyCounters = 7,
+}
+
+#[contracttype]
+#[derive(Clone)]
+enum DataKey {
+    /// The global counter.
+    Count,
+    /// Counter owned by a single user, in the given reset epoch.
+    UserCount(u32, Address),
+    /// Independent counter identified by name.
+    Named(Symbol),
+    /// Names of all named counters.
+    Names,
+    /// Slot in the history ring buffer.
+    History(u32),
+    /// Total number of history entries ever recorded.
+    HistoryLen,
+    /// The signed counter, which may go below zero.
+    SignedCount,
+}
+
+const DAY_IN_LEDGERS: u32 = 17280;
+/// Counter entries whose TTL falls below this many ledgers are extended.
+const BUMP_THRESHOLD: u32 = 7 * DAY_IN_LEDGERS;
+/// Number of ledgers counter entries are extended to live for.
+const BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+
+/// Number of changes retained in the history ring buffer.
+const HISTORY_SIZE: u32 = 20;
+
+/// Maximum number of named counters, which keeps the name index and every
+/// loop over it (`emergency_reset`, `export_state`, `import_state`) within a
+/// single transaction's footprint.
+const MAX_NAMED_COUNTERS: u32 = 20;
+
+/// A single change to the global counter.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HistoryEntry {
+    /// Ledger sequence the change happened in.
+    pub ledger: u32,
+    /// Address that made the change.
+    pub invoker: Address,
+    /// Signed difference between the new and old values.
+    pub delta: i64,
+}
+
+/// Snapshot of the counter configuration and values, used to migrate state
+/// between contract instances.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CounterState {
+    pub count: u32,
+    pub step: u32,
+    pub max: Option<u32>,
+    pub named: Map<Symbol, u32>,
+    pub signed_count: i128,
+}
+
+/// How the counter behaves when an increment would exceed `u32::MAX`.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum OverflowMode {
+    /// Revert the invocation.
+    Panic,
+    /// Stay at `u32::MAX`.
+    Saturate,
+    /// Roll over to zero.
+    Wrap,
+}
+
+/// Optional token burn applied for every unit the counter is decremented by.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BurnConfig {
+    /// Amount of the configured token burned from the caller per unit.
+    pub amount: i128,
+    /// Whether the burn is applied.
+    pub enabled: bool,
+}
+
+/// Optional token fee paid to a treasury for every unit the counter is
+/// incremented by.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeConfig {
+    /// Amount of the configured token transferred from the caller per unit.
+    pub amount: i128,
+    /// Address that receives the fee.
+    pub treasury: Address,
+}
+
+/// Read a persistent counter entry, extending its TTL if it exists. The
+/// contract instance is extended too, since every counter depends on it.
+fn read_persistent<V: TryFromVal<Env, Val>>(env: &Env, key: &DataKey) -> Option<V> {
+    env.storage()
+        .instance()
+        .extend_ttl(BUMP_THRESHOLD, BUMP_AMOUNT);
+
+    let storage = env.storage().persistent();
+    let value = storage.get(key);
+    if value.is_some() {
+        storage.extend_ttl(key, BUMP_THRESHOLD, BUMP_AMOUNT);
+    }
+    value
+}
+
+/// Write a persistent counter entry and extend its TTL, along with the
+/// contract instance.
+fn write_persistent<V: IntoVal<Env, Val>>(env: &Env, key: &DataKey, value: &V) {
+    env.storage()
+        .instance()
+        .extend_ttl(BUMP_THRESHOLD, BUMP_AMOUNT);
+
+    let storage = env.storage().persistent();
+    storage.set(key, value);
+    storage.extend_ttl(key, BUMP_THRESHOLD, BUMP_AMOUNT);
+}
+
+/// Add `delta` to `count` according to the configured overflow mode.
+fn add_with_mode(mode: OverflowMode, count: u32, delta: u32) -> Result<u32, CounterError> {
+    match mode {
+        OverflowMode::Panic => count.checked_add(delta).ok_or(CounterError::Overflow),
+        OverflowMode::Saturate => Ok(count.saturating_add(delta)),
+        OverflowMode::Wrap => Ok(count.wrapping_add(delta)),
+    }
+}
 
 #[contract]
 pub struct Increment;
 
 #[contractimpl]
 impl Increment {
-    /// Increment increments an internal counter, returning the new value.
-    pub fn increment(env: Env) -> u32 {
-        // Get the current count.
-        let mut count: u32 = env
-            .storage()
+    /// Constructor - stores the admin allowed to perform privileged operations
+    /// and how increments behave on overflow, which is fixed for the life of
+    /// the contract so the admin cannot change it under a live counter.
+    pub fn __constructor(env: Env, admin: Address, overflow_mode: OverflowMode) {
+        let storage = env.storage().instance();
+        storage.set(&symbol_short!("admin"), &admin);
+        storage.set(&symbol_short!("overflow"), &overflow_mode);
+    }
+
+    /// Get the admin address.
+    pub fn get_admin(env: Env) -> Address {
+        env.storage()
             .instance()
-            .get(&symbol_short!("count"))
-            .unwrap_or(0); // If no value set, assume 0.
+            .get(&symbol_short!("admin"))
+            .unwrap_or_else(|| panic!("Admin not set"))
+    }
 
-        // Increment the count.
-        count += 1;
+    /// Set the token contract used for holder-gated increments (admin only).
+    pub fn set_token(env: Env, token: Address) {
+        Self::get_admin(env.clone()).require_auth();
+        env.storage()
+            .instance()
+            .set(&symbol_short!("token"), &token);
+    }
 
-        // Save the count.
-        env.storage().instance().set(&symbol_short!("count"), &count);
+    /// Get the configured token contract address.
+    pub fn get_token(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("token"))
+            .unwrap_or_else(|| panic!("Token not set"))
+    }
 
-        // Return the count to the caller.
-        count
+    /// Configure the token burn applied for every unit a caller lowers the
+    /// global counter by (admin only).
+    pub fn set_decrement_burn(env: Env, amount: i128, enabled: bool) {
+        Self::get_admin(env.clone()).require_auth();
+        if amount < 0 {
+            panic!("Burn amount must not be negative");
+        }
+        env.storage()
+            .instance()
+            .set(&symbol_short!("burn"), &BurnConfig { amount, enabled });
     }
 
-    /// Decrement decrements an internal counter, returning the new value.
-    pub fn decrement(env: Env) -> u32 {
-        // Get the current count.
-        let mut count: u32 = env
-            .storage()
+    /// Get the decrement burn configuration, disabled by default.
+    pub fn get_decrement_burn(env: Env) -> BurnConfig {
+        env.storage()
             .instance()
-            .get(&symbol_short!("count"))
-            .unwrap_or(0); // If no value set, assume 0.
+            .get(&symbol_short!("burn"))
+            .unwrap_or(BurnConfig {
+                amount: 0,
+                enabled: false,
+            })
+    }
 
-        // Decrement the count (but don't go below 0).
-        if count > 0 {
-            count -= 1;
+    /// Charge `amount` of the configured token, paid to `treasury`, for every
+    /// unit a caller raises the global counter by (admin only). With a step of
+    /// 5, `increment` pays the same as `increment_by(5)`; the admin's
+    /// `reset_to` and `import_state` are free.
+    pub fn set_increment_fee(env: Env, amount: i128, treasury: Address) {
+        Self::get_admin(env.clone()).require_auth();
+        if amount <= 0 {
+            panic!("Fee must be positive");
         }
+        env.storage()
+            .instance()
+            .set(&symbol_short!("fee"), &FeeConfig { amount, treasury });
+    }
 
-        // Save the count.
-        env.storage().instance().set(&symbol_short!("count"), &count);
+    /// Stop charging a fee on increment (admin only).
+    pub fn clear_increment_fee(env: Env) {
+        Self::get_admin(env.clone()).require_auth();
+        env.storage().instance().remove(&symbol_short!("fee"));
+    }
 
-        // Return the count to the caller.
-        count
+    /// Get the increment fee, if one is configured.
+    pub fn get_increment_fee(env: Env) -> Option<FeeConfig> {
+        env.storage().instance().get(&symbol_short!("fee"))
     }
 
-    /// Reset resets the counter to zero.
-    pub fn reset(env: Env) {
-        env.storage().instance().set(&symbol_short!("count"), &0);
+    /// Set the amount `increment` and `decrement` move the counter by (admin only).
+    pub fn set_step(env: Env, step: u32) -> Result<(), CounterError> {
+        Self::get_admin(env.clone()).require_auth();
+        if step == 0 {
+            return Err(CounterError::InvalidStep);
+        }
+
+        let old = Self::get_step(env.clone());
+        env.storage().instance().set(&symbol_short!("step"), &step);
+        env.events()
+            .publish((symbol_short!("set_step"),), (old, step));
+        Ok(())
+    }
+
+    /// Get the step size, defaulting to 1.
+    pub fn get_step(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("step"))
+            .unwrap_or(1)
+    }
+
+    /// Set the maximum value the counter may reach (admin only).
+    pub fn set_max(env: Env, max: u32) {
+        Self::get_admin(env.clone()).require_auth();
+        env.storage().instance().set(&symbol_short!("max"), &max);
+    }
+
+    /// Get the configured maximum, if any.
+    pub fn get_max(env: Env) -> Option<u32> {
+        env.storage().instance().get(&symbol_short!("max"))
+    }
+
+    /// Get the overflow mode chosen at deployment, defaulting to `Panic`.
+    pub fn get_overflow_mode(env: Env) -> OverflowMode {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("overflow"))
+            .unwrap_or(OverflowMode::Panic)
+    }
+
+    /// Pause all counter updates (admin only).
+    pub fn pause(env: Env) {
+        Self::get_admin(env.clone()).require_auth();
+        env.storage()
+            .instance()
+            .set(&symbol_short!("paused"), &true);
+        env.events().publish((symbol_short!("paused"),), ());
+    }
+
+    /// Resume counter updates (admin only).
+    pub fn unpause(env: Env) {
+        Self::get_admin(env.clone()).require_auth();
+        env.storage().instance().remove(&symbol_short!("paused"));
+        env.events().publish((symbol_short!("unpaused"),), ());
+    }
+
+    /// Whether counter updates are paused.
+    pub fn is_paused(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("paused"))
+            .unwrap_or(false)
+    }
+
+    /// Increment the counter only if `user` holds at least `min_balance`
+    /// of the configured token, returning the new value.
+    pub fn increment_if_holder(
+        env: Env,
+        user: Address,
+        min_balance: i128,
+    ) -> Result<u32, CounterError> {
+        user.require_auth();
+        Self::require_not_paused(&env)?;
+
+        let token = token::Client::new(&env, &Self::get_token(env.clone()));
+        if token.balance(&user) < min_balance {
+            return Err(CounterError::InsufficientBalance);
+        }
+
+        Self::increment_count(&env, &user, Self::get_step(env.clone()))
+    }
+
+    /// Increment increments an internal counter by the step size, returning
+    /// the new value.
+    pub fn increment(env: Env, caller: Address) -> Result<u32, CounterError> {
+        caller.require_auth();
+        Self::require_not_paused(&env)?;
+        Self::increment_count(&env, &caller, Self::get_step(env.clone()))
+    }
+
+    /// Decrement decrements an internal counter by the step size, returning
+    /// the new value. When the decrement burn is enabled, the configured
+    /// token amount is burned from `caller` once per unit decremented.
+    pub fn decrement(env: Env, caller: Address) -> Result<u32, CounterError> {
+        caller.require_auth();
+        Self::require_not_paused(&env)?;
+        Ok(Self::decrement_count(
+            &env,
+            &caller,
+            Self::get_step(env.clone()),
+        ))
+    }
+
+    /// Apply `n` increments of the step in one invocation and return the
+    /// value after each one. Every increment is recorded, emits an event and
+    /// pays the increment fee, so the cost of a call grows linearly with `n`.
+    pub fn increment_times(env: Env, caller: Address, n: u32) -> Result<Vec<u32>, CounterError> {
+        caller.require_auth();
+        Self::require_not_paused(&env)?;
+
+        let step = Self::get_step(env.clone());
+        let mut values = Vec::new(&env);
+        for _ in 0..n {
+            values.push_back(Self::increment_count(&env, &caller, step)?);
+        }
+        Ok(values)
+    }
+
+    /// Increment the counter by `amount`, returning the new value. When an
+    /// increment fee is configured, it is paid once per unit incremented.
+    pub fn increment_by(env: Env, caller: Address, amount: u32) -> Result<u32, CounterError> {
+        caller.require_auth();
+        Self::require_not_paused(&env)?;
+        Self::increment_count(&env, &caller, amount)
+    }
+
+    /// Decrement the counter by `amount`, stopping at zero, and return the
+    /// new value. When the decrement burn is enabled, the configured token
+    /// amount is burned from `caller` once per unit decremented.
+    pub fn decrement_by(env: Env, caller: Address, amount: u32) -> Result<u32, CounterError> {
+        caller.require_auth();
+        Self::require_not_paused(&env)?;
+        Ok(Self::decrement_count(&env, &caller, amount))
+    }
+
+    /// Increment `user`'s own counter, returning the new value. Publishes an
+    /// `increment_for` event with the user and the old and new values.
+    pub fn increment_for(env: Env, user: Address) -> Result<u32, CounterError> {
+        user.require_auth();
+        Self::require_not_paused(&env)?;
+        Self::increment_user(&env, user)
+    }
+
+    /// Increment the own counter of every user in `users`, each of whom must
+    /// authorize the call, and return the new values in order. A user listed
+    /// more than once authorizes once and is incremented for every entry.
+    pub fn increment_for_many(env: Env, users: Vec<Address>) -> Result<Vec<u32>, CounterError> {
+        Self::require_not_paused(&env)?;
+
+        let mut authorized = Vec::new(&env);
+        let mut counts = Vec::new(&env);
+        for user in users.iter() {
+            // require_auth may only be called once per address in a frame.
+            if !authorized.contains(&user) {
+                user.require_auth();
+                authorized.push_back(user.clone());
+            }
+            counts.push_back(Self::increment_user(&env, user)?);
+        }
+        Ok(counts)
+    }
+
+    /// Get `user`'s own counter.
+    pub fn get_count_for(env: Env, user: Address) -> u32 {
+        read_persistent(&env, &Self::user_key(&env, user)).unwrap_or(0)
+    }
+
+    /// Increment the counter called `name`, creating it on first use, and
+    /// return its new value. At most `MAX_NAMED_COUNTERS` names can exist.
+    /// Publishes an `increment_named` event with the name and the old and
+    /// new values; named counters take no invoker.
+    pub fn increment_named(env: Env, name: Symbol) -> Result<u32, CounterError> {
+        Self::require_not_paused(&env)?;
+
+        if !Self::has_named(&env, &name) {
+            let mut names = Self::list_counters(env.clone());
+            if names.len() >= MAX_NAMED_COUNTERS {
+                return Err(CounterError::TooManyCounters);
+            }
+            names.push_back(name.clone());
+            write_persistent(&env, &DataKey::Names, &names);
+        }
+
+        let old = Self::get_named(env.clone(), name.clone());
+        let count = add_with_mode(Self::get_overflow_mode(env.clone()), old, 1)?;
+        write_persistent(&env, &DataKey::Named(name.clone()), &count);
+        env.events()
+            .publish((Symbol::new(&env, "increment_named"), name), (old, count));
+        Ok(count)
+    }
+
+    /// Get the value of the counter called `name`.
+    pub fn get_named(env: Env, name: Symbol) -> u32 {
+        read_persistent(&env, &DataKey::Named(name)).unwrap_or(0)
+    }
+
+    /// List the names of all named counters.
+    pub fn list_counters(env: Env) -> Vec<Symbol> {
+        read_persistent(&env, &DataKey::Names).unwrap_or(Vec::new(&env))
+    }
+
+    /// Reset resets the counter to zero (admin only).
+    pub fn reset(env: Env, caller: Address) -> Result<(), CounterError> {
+        caller.require_auth();
+        if caller != Self::get_admin(env.clone()) {
+            return Err(CounterError::NotAdmin);
+        }
+
+        let old = Self::get_count(env.clone());
+        Self::set_count(&env, &caller, symbol_short!("reset"), old, 0);
+        Ok(())
+    }
+
+    /// Set the counter to `value` (admin only), for correcting it after an
+    /// operational mistake. The value must not exceed the configured max.
+    pub fn reset_to(env: Env, caller: Address, value: u32) -> Result<(), CounterError> {
+        caller.require_auth();
+        if caller != Self::get_admin(env.clone()) {
+            return Err(CounterError::NotAdmin);
+        }
+        if Self::get_max(env.clone()).is_some_and(|max| value > max) {
+            return Err(CounterError::LimitReached);
+        }
+
+        let old = Self::get_count(env.clone());
+        Self::set_count(&env, &caller, symbol_short!("reset_to"), old, value);
+        Ok(())
+    }
+
+    /// Emergency reset clears all counter state in one call (admin only).
+    /// Intended for recovering from corrupted state.
+    ///
+    /// Per-user counters are not enumerated: the reset moves to a new epoch,
+    /// so the old entries are no longer read and are left to expire. Every
+    /// other entry is bounded, so the call fits in a single transaction no
+    /// matter how many users the counter has.
+    pub fn emergency_reset(env: Env) {
+        Self::get_admin(env.clone()).require_auth();
+
+        let storage = env.storage().persistent();
+        storage.remove(&DataKey::Count);
+        storage.remove(&DataKey::SignedCount);
+        let epoch = Self::reset_epoch(&env);
+        env.storage()
+            .instance()
+            .set(&symbol_short!("epoch"), &epoch.wrapping_add(1));
+        Self::clear_named(&env);
+        Self::clear_history(&env);
+
+        env.events()
+            .publish((Symbol::new(&env, "emergency_reset"),), ());
+    }
+
+    /// Increment the signed counter by the step and return the new value.
+    pub fn signed_increment(env: Env, caller: Address) -> Result<i128, CounterError> {
+        caller.require_auth();
+        Self::require_not_paused(&env)?;
+        Self::adjust_signed(&env, &caller, i128::from(Self::get_step(env.clone())))
+    }
+
+    /// Decrement the signed counter by the step and return the new value.
+    /// Unlike `decrement`, the result may be negative.
+    pub fn signed_decrement(env: Env, caller: Address) -> Result<i128, CounterError> {
+        caller.require_auth();
+        Self::require_not_paused(&env)?;
+        Self::adjust_signed(&env, &caller, -i128::from(Self::get_step(env.clone())))
+    }
+
+    /// Get the current signed count.
+    pub fn get_signed_count(env: Env) -> i128 {
+        read_persistent(&env, &DataKey::SignedCount).unwrap_or(0)
     }
 
     /// Get the current count.
     pub fn get_count(env: Env) -> u32 {
+        read_persistent(&env, &DataKey::Count).unwrap_or(0)
+    }
+
+    /// Get up to `limit` of the most recent changes to the global counter,
+    /// oldest first, skipping the first `start` retained entries. Only the
+    /// last `HISTORY_SIZE` changes are kept.
+    pub fn get_history(env: Env, start: u32, limit: u32) -> Vec<HistoryEntry> {
+        let total = Self::history_len(&env);
+        let retained = total.min(HISTORY_SIZE);
+        let oldest = total - retained;
+
+        let mut entries = Vec::new(&env);
+        let end = retained.min(start.saturating_add(limit));
+        for i in start..end {
+            let slot = (oldest + i) % HISTORY_SIZE;
+            if let Some(entry) = read_persistent(&env, &DataKey::History(slot)) {
+                entries.push_back(entry);
+            }
+        }
+        entries
+    }
+
+    /// Export the counter state so it can be imported into another instance.
+    pub fn export_state(env: Env) -> CounterState {
+        let mut named = Map::new(&env);
+        for name in Self::list_counters(env.clone()).iter() {
+            named.set(name.clone(), Self::get_named(env.clone(), name));
+        }
+
+        CounterState {
+            count: Self::get_count(env.clone()),
+            step: Self::get_step(env.clone()),
+            max: Self::get_max(env.clone()),
+            named,
+            signed_count: Self::get_signed_count(env),
+        }
+    }
+
+    /// Restore counter state exported from another instance (admin only).
+    /// The state is validated like the individual setters before anything is
+    /// written.
+    pub fn import_state(env: Env, state: CounterState) -> Result<(), CounterError> {
+        Self::get_admin(env.clone()).require_auth();
+        if state.step == 0 {
+            return Err(CounterError::InvalidStep);
+        }
+        if state.max.is_some_and(|max| state.count > max) {
+            return Err(CounterError::LimitReached);
+        }
+        if state.named.len() > MAX_NAMED_COUNTERS {
+            return Err(CounterError::TooManyCounters);
+        }
+        write_persistent(&env, &DataKey::Count, &state.count);
+        write_persistent(&env, &DataKey::SignedCount, &state.signed_count);
+        env.storage()
+            .instance()
+            .set(&symbol_short!("step"), &state.step);
+        match state.max {
+            Some(max) => env.storage().instance().set(&symbol_short!("max"), &max),
+            None => env.storage().instance().remove(&symbol_short!("max")),
+        }
+
+        Self::clear_named(&env);
+        for (name, count) in state.named.iter() {
+            write_persistent(&env, &DataKey::Named(name), &count);
+        }
+        write_persistent(&env, &DataKey::Names, &state.named.keys());
+        Ok(())
+    }
+
+    /// Returns the standard interfaces this contract implements, so tooling
+    /// can feature-detect at runtime.
+    pub fn supported_interfaces(env: Env) -> Vec<Symbol> {
+        vec![&env, symbol_short!("pausable")]
+    }
+}
+
+impl Increment {
+    /// Fail with `ContractPaused` while counter updates are paused.
+    fn require_not_paused(env: &Env) -> Result<(), CounterError> {
+        if Self::is_paused(env.clone()) {
+            return Err(CounterError::ContractPaused);
+        }
+        Ok(())
+    }
+
+    /// Whether a counter called `name` has been created.
+    fn has_named(env: &Env, name: &Symbol) -> bool {
+        env.storage()
+            .persistent()
+            .has(&DataKey::Named(name.clone()))
+    }
+
+    /// Remove every named counter and the name index.
+    fn clear_named(env: &Env) {
+        let storage = env.storage().persistent();
+        for name in Self::list_counters(env.clone()).iter() {
+            storage.remove(&DataKey::Named(name));
+        }
+        storage.remove(&DataKey::Names);
+    }
+
+    /// Number of emergency resets performed, which scopes per-user counters.
+    fn reset_epoch(env: &Env) -> u32 {
         env.storage()
             .instance()
-            .get(&symbol_short!("count"))
+            .get(&symbol_short!("epoch"))
             .unwrap_or(0)
     }
+
+    /// Storage key of `user`'s counter in the current reset epoch.
+    fn user_key(env: &Env, user: Address) -> DataKey {
+        DataKey::UserCount(Self::reset_epoch(env), user)
+    }
+
+    /// Add one to `user`'s own counter.
+    fn increment_user(env: &Env, user: Address) -> Result<u32, CounterError> {
+        let key = Self::user_key(env, user.clone());
+        let old: u32 = read_persistent(env, &key).unwrap_or(0);
+
+        let count = add_with_mode(Self::get_overflow_mode(env.clone()), old, 1)?;
+        write_persistent(env, &key, &count);
+        env.events()
+            .publish((Symbol::new(env, "increment_for"), user), (old, count));
+        Ok(count)
+    }
+
+    /// Add `delta` to the global counter on behalf of `caller`, who pays the
+    /// increment fee for the units the counter actually moved.
+    fn increment_count(env: &Env, caller: &Address, delta: u32) -> Result<u32, CounterError> {
+        // Get the current count.
+        let old = Self::get_count(env.clone());
+
+        // Increment the count, honouring the maximum and overflow mode.
+        let count = Self::increase(env, old, delta)?;
+
+        // Charge for the actual change: less than `delta` when saturating,
+        // and all of it when wrapping past zero.
+        Self::pay_increment_fee(env, caller, count.wrapping_sub(old));
+
+        // Save the count and return it to the caller.
+        Self::set_count(env, caller, symbol_short!("increment"), old, count);
+        Ok(count)
+    }
+
+    /// Subtract `delta` from the global counter on behalf of `caller`,
+    /// stopping at zero. The decrement burn covers only the units actually
+    /// removed.
+    fn decrement_count(env: &Env, caller: &Address, delta: u32) -> u32 {
+        // Get the current count.
+        let old = Self::get_count(env.clone());
+
+        // Decrement the count (but don't go below 0).
+        let count = old.saturating_sub(delta);
+        Self::burn_for_decrement(env, caller, old - count);
+
+        // Save the count and return it to the caller.
+        Self::set_count(env, caller, symbol_short!("decrement"), old, count);
+        count
+    }
+
+    /// Store the global counter and publish a change event carrying the
+    /// invoker and the old and new values.
+    fn set_count(env: &Env, caller: &Address, action: Symbol, old: u32, new: u32) {
+        write_persistent(env, &DataKey::Count, &new);
+        Self::record_history(env, caller, i64::from(new) - i64::from(old));
+        env.events().publish((action, caller.clone()), (old, new));
+    }
+
+    /// Add `delta` to the signed counter on behalf of `caller` and publish a
+    /// `signed` event carrying the old and new values.
+    fn adjust_signed(env: &Env, caller: &Address, delta: i128) -> Result<i128, CounterError> {
+        let old = Self::get_signed_count(env.clone());
+        let new = old.checked_add(delta).ok_or(CounterError::Overflow)?;
+        write_persistent(env, &DataKey::SignedCount, &new);
+        env.events()
+            .publish((symbol_short!("signed"), caller.clone()), (old, new));
+        Ok(new)
+    }
+
+    /// Number of history entries ever recorded.
+    fn history_len(env: &Env) -> u32 {
+        read_persistent(env, &DataKey::HistoryLen).unwrap_or(0)
+    }
+
+    /// Append a change to the history ring buffer, overwriting the oldest
+    /// entry once it is full.
+    fn record_history(env: &Env, invoker: &Address, delta: i64) {
+        let total = Self::history_len(env);
+        let entry = HistoryEntry {
+            ledger: env.ledger().sequence(),
+            invoker: invoker.clone(),
+            delta,
+        };
+
+        write_persistent(env, &DataKey::History(total % HISTORY_SIZE), &entry);
+        write_persistent(env, &DataKey::HistoryLen, &total.wrapping_add(1));
+    }
+
+    /// Remove every history entry.
+    fn clear_history(env: &Env) {
+        let storage = env.storage().persistent();
+        for slot in 0..Self::history_len(env).min(HISTORY_SIZE) {
+            storage.remove(&DataKey::History(slot));
+        }
+        storage.remove(&DataKey::HistoryLen);
+    }
+
+    /// Add `delta` to the global `count`, failing with `LimitReached` past the
+    /// configured maximum and otherwise applying the overflow mode.
+    fn increase(env: &Env, count: u32, delta: u32) -> Result<u32, CounterError> {
+        if let Some(max) = Self::get_max(env.clone()) {
+            if u64::from(count) + u64::from(delta) > u64::from(max) {
+                return Err(CounterError::LimitReached);
+            }
+        }
+        add_with_mode(Self::get_overflow_mode(env.clone()), count, delta)
+    }
+
+    /// Transfer the increment fee for `units` units from `caller` to the
+    /// treasury, if an increment fee is configured.
+    fn pay_increment_fee(env: &Env, caller: &Address, units: u32) {
+        let Some(fee) = Self::get_increment_fee(env.clone()) else {
+            return;
+        };
+        if units == 0 {
+            return;
+        }
+
+        let amount = fee
+            .amount
+            .checked_mul(units.into())
+            .unwrap_or_else(|| panic_with_error!(env, CounterError::Overflow));
+        token::Client::new(env, &Self::get_token(env.clone())).transfer(
+            caller,
+            &fee.treasury,
+            &amount,
+        );
+    }
+
+    /// Burn the configured token amount from `caller` for `units` units, if
+    /// the decrement burn is enabled.
+    fn burn_for_decrement(env: &Env, caller: &Address, units: u32) {
+        let burn = Self::get_decrement_burn(env.clone());
+        if !burn.enabled || burn.amount == 0 || units == 0 {
+            return;
+        }
+
+        let amount = burn
+            .amount
+            .checked_mul(units.into())
+            .unwrap_or_else(|| panic_with_error!(env, CounterError::Overflow));
+        token::Client::new(env, &Self::get_token(env.clone())).burn(caller, &amount);
+    }
 }
 
 #[cfg(test)]
@@ -1,40 +1,1522 @@
-use soroban_sdk::Env;
+extern crate std;
 
-use crate::{Increment, IncrementClient};
+use soroban_sdk::{
+    contract, contractimpl, map, symbol_short,
+    testutils::{
+        storage::Persistent as _, Address as _, AuthorizedFunction, AuthorizedInvocation, Events,
+        Ledger, MockAuth, MockAuthInvoke,
+    },
+    token::{StellarAssetClient, TokenClient},
+    vec, Address, Env, IntoVal, Map, Symbol,
+};
+
+use crate::{
+    BurnConfig, CounterError, CounterState, DataKey, FeeConfig, HistoryEntry, Increment,
+    IncrementClient, OverflowMode, BUMP_AMOUNT, BUMP_THRESHOLD, HISTORY_SIZE, MAX_NAMED_COUNTERS,
+};
+
+/// Minimal token exposing the balance, burn and supply views the tests need.
+#[contract]
+struct MockToken;
+
+#[contractimpl]
+impl MockToken {
+    pub fn mint(env: Env, to: Address, amount: i128) {
+        let balance = Self::balance(env.clone(), to.clone());
+        env.storage().persistent().set(&to, &(balance + amount));
+        let supply = Self::total_supply(env.clone());
+        env.storage().instance().set(&(), &(supply + amount));
+    }
+
+    pub fn burn(env: Env, from: Address, amount: i128) {
+        from.require_auth();
+        let balance = Self::balance(env.clone(), from.clone());
+        if balance < amount {
+            panic!("Insufficient balance");
+        }
+        env.storage().persistent().set(&from, &(balance - amount));
+        let supply = Self::total_supply(env.clone());
+        env.storage().instance().set(&(), &(supply - amount));
+    }
+
+    pub fn balance(env: Env, id: Address) -> i128 {
+        env.storage().persistent().get(&id).unwrap_or(0)
+    }
+
+    pub fn total_supply(env: Env) -> i128 {
+        env.storage().instance().get(&()).unwrap_or(0)
+    }
+}
+
+fn setup(env: &Env) -> (Address, IncrementClient<'_>) {
+    setup_with_mode(env, OverflowMode::Panic)
+}
+
+fn setup_with_mode(env: &Env, mode: OverflowMode) -> (Address, IncrementClient<'_>) {
+    let admin = Address::generate(env);
+    let contract_id = env.register(Increment, (&admin, mode));
+    (admin, IncrementClient::new(env, &contract_id))
+}
+
+fn create_token(env: &Env) -> StellarAssetClient<'_> {
+    let issuer = Address::generate(env);
+    let sac = env.register_stellar_asset_contract_v2(issuer);
+    StellarAssetClient::new(env, &sac.address())
+}
 
 #[test]
 fn test_increment() {
     let env = Env::default();
-    let contract_id = env.register_contract(None, Increment);
-    let client = IncrementClient::new(&env, &contract_id);
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let caller = Address::generate(&env);
 
     // Test increment
-    assert_eq!(client.increment(), 1);
-    assert_eq!(client.increment(), 2);
+    assert_eq!(client.increment(&caller), 1);
+    assert_eq!(client.increment(&caller), 2);
 }
 
 #[test]
 fn test_decrement() {
     let env = Env::default();
-    let contract_id = env.register_contract(None, Increment);
-    let client = IncrementClient::new(&env, &contract_id);
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let caller = Address::generate(&env);
 
     // Test decrement
-    assert_eq!(client.decrement(), 0); // Can't go below 0
-    assert_eq!(client.increment(), 1);
-    assert_eq!(client.decrement(), 0);
+    assert_eq!(client.decrement(&caller), 0); // Can't go below 0
+    assert_eq!(client.increment(&caller), 1);
+    assert_eq!(client.decrement(&caller), 0);
 }
 
 #[test]
 fn test_reset() {
     let env = Env::default();
-    let contract_id = env.register_contract(None, Increment);
-    let client = IncrementClient::new(&env, &contract_id);
+    env.mock_all_auths();
+    let (admin, client) = setup(&env);
+    let caller = Address::generate(&env);
 
     // Test reset
-    client.increment();
-    client.increment();
-    client.reset();
-    // Note: We can't test get_count() due to the conversion error
-    // but we can test that reset doesn't crash
+    client.increment(&caller);
+    client.increment(&caller);
+    client.reset(&admin);
+    assert_eq!(client.get_count(), 0);
+}
+
+#[test]
+fn test_reset_not_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let caller = Address::generate(&env);
+
+    client.increment(&caller);
+    assert_eq!(client.try_reset(&caller), Err(Ok(CounterError::NotAdmin)));
+    assert_eq!(client.get_count(), 1);
+}
+
+#[test]
+fn test_reset_to() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, client) = setup(&env);
+    let caller = Address::generate(&env);
+    client.increment(&caller);
+
+    client.reset_to(&admin, &42);
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (symbol_short!("reset_to"), admin.clone()).into_val(&env),
+                (1_u32, 42_u32).into_val(&env),
+            ),
+        ]
+    );
+    assert_eq!(client.get_count(), 42);
+    assert_eq!(client.increment(&caller), 43);
+}
+
+#[test]
+fn test_reset_to_not_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let caller = Address::generate(&env);
+
+    assert_eq!(
+        client.try_reset_to(&caller, &42),
+        Err(Ok(CounterError::NotAdmin))
+    );
+    assert_eq!(client.get_count(), 0);
+}
+
+#[test]
+fn test_reset_to_above_max() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, client) = setup(&env);
+    client.set_max(&10);
+
+    assert_eq!(
+        client.try_reset_to(&admin, &11),
+        Err(Ok(CounterError::LimitReached))
+    );
+    client.reset_to(&admin, &10);
+    assert_eq!(client.get_count(), 10);
+}
+
+#[test]
+#[should_panic]
+fn test_reset_requires_admin_auth() {
+    let env = Env::default();
+    let (admin, client) = setup(&env);
+
+    client.reset(&admin);
+}
+
+#[test]
+fn test_get_admin() {
+    let env = Env::default();
+    let (admin, client) = setup(&env);
+
+    assert_eq!(client.get_admin(), admin);
+}
+
+#[test]
+fn test_emergency_reset() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, client) = setup(&env);
+    let caller = Address::generate(&env);
+
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    client.increment(&caller);
+    client.increment(&caller);
+    client.increment(&caller);
+    client.increment_for(&alice);
+    client.increment_for(&bob);
+    client.increment_for(&bob);
+    client.increment_named(&symbol_short!("likes"));
+    client.increment_named(&symbol_short!("views"));
+    assert_eq!(client.get_count(), 3);
+
+    client.emergency_reset();
+    assert_eq!(
+        env.auths()[0].1.function,
+        AuthorizedFunction::Contract((
+            client.address.clone(),
+            Symbol::new(&env, "emergency_reset"),
+            vec![&env],
+        ))
+    );
+    assert_eq!(env.auths()[0].0, admin);
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (Symbol::new(&env, "emergency_reset"),).into_val(&env),
+                ().into_val(&env),
+            ),
+        ]
+    );
+
+    assert_eq!(client.get_count(), 0);
+    assert_eq!(client.get_count_for(&alice), 0);
+    assert_eq!(client.get_count_for(&bob), 0);
+    assert_eq!(client.get_named(&symbol_short!("likes")), 0);
+    assert_eq!(client.get_named(&symbol_short!("views")), 0);
+    assert!(client.list_counters().is_empty());
+    assert!(client.get_history(&0, &HISTORY_SIZE).is_empty());
+
+    // Counters start from scratch after the reset.
+    assert_eq!(client.increment_for(&bob), 1);
+}
+
+#[test]
+#[should_panic]
+fn test_emergency_reset_requires_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let caller = Address::generate(&env);
+
+    client.increment(&caller);
+
+    // Drop the mocked auths so the admin check is enforced.
+    env.set_auths(&[]);
+    client.emergency_reset();
+}
+
+#[test]
+fn test_increment_if_holder() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let token = create_token(&env);
+    client.set_token(&token.address);
+    assert_eq!(client.get_token(), token.address);
+
+    let user = Address::generate(&env);
+    token.mint(&user, &100);
+
+    assert_eq!(client.increment_if_holder(&user, &100), 1);
+    assert_eq!(client.increment_if_holder(&user, &50), 2);
+    assert_eq!(client.get_count(), 2);
+}
+
+#[test]
+fn test_increment_if_holder_underfunded() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let token = create_token(&env);
+    client.set_token(&token.address);
+
+    let user = Address::generate(&env);
+    token.mint(&user, &99);
+
+    assert_eq!(
+        client.try_increment_if_holder(&user, &100),
+        Err(Ok(CounterError::InsufficientBalance))
+    );
+    assert_eq!(client.get_count(), 0);
+}
+
+#[test]
+fn test_supported_interfaces() {
+    let env = Env::default();
+    let (_, client) = setup(&env);
+
+    assert_eq!(
+        client.supported_interfaces(),
+        vec![&env, symbol_short!("pausable")]
+    );
+}
+
+#[test]
+fn test_export_import_state() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, source) = setup(&env);
+    let caller = Address::generate(&env);
+    source.increment(&caller);
+    source.increment(&caller);
+
+    let state = source.export_state();
+    assert_eq!(
+        state,
+        CounterState {
+            count: 2,
+            step: 1,
+            max: None,
+            named: Map::new(&env),
+            signed_count: 0,
+        }
+    );
+
+    let (_, target) = setup(&env);
+    target.import_state(&state);
+    assert_eq!(target.export_state(), state);
+    assert_eq!(target.get_count(), 2);
+    assert_eq!(target.increment(&caller), 3);
+}
+
+#[test]
+fn test_export_import_signed_count() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, source) = setup(&env);
+    let caller = Address::generate(&env);
+    source.signed_decrement(&caller);
+    source.signed_decrement(&caller);
+
+    let state = source.export_state();
+    assert_eq!(state.signed_count, -2);
+
+    let (_, target) = setup(&env);
+    target.signed_increment(&caller);
+    target.import_state(&state);
+    assert_eq!(target.get_signed_count(), -2);
+    assert_eq!(target.signed_decrement(&caller), -3);
+}
+
+#[test]
+fn test_import_state_rejects_invalid_state() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let valid = CounterState {
+        count: 5,
+        step: 1,
+        max: Some(10),
+        named: Map::new(&env),
+        signed_count: 0,
+    };
+
+    let mut zero_step = valid.clone();
+    zero_step.step = 0;
+    let mut above_max = valid.clone();
+    above_max.count = 11;
+    let mut too_many_named = valid.clone();
+    for i in 0..=MAX_NAMED_COUNTERS {
+        too_many_named
+            .named
+            .set(Symbol::new(&env, &std::format!("counter_{i}")), 1);
+    }
+
+    for (state, error) in [
+        (zero_step, CounterError::InvalidStep),
+        (above_max, CounterError::LimitReached),
+        (too_many_named, CounterError::TooManyCounters),
+    ] {
+        assert_eq!(client.try_import_state(&state), Err(Ok(error)));
+    }
+    // Nothing was written by the rejected imports.
+    assert_eq!(client.get_count(), 0);
+    assert_eq!(client.get_max(), None);
+    assert!(client.list_counters().is_empty());
+
+    client.import_state(&valid);
+    assert_eq!(client.export_state(), valid);
+}
+
+#[test]
+#[should_panic]
+fn test_import_state_requires_admin() {
+    let env = Env::default();
+    let (_, client) = setup(&env);
+
+    client.import_state(&CounterState {
+        count: 5,
+        step: 1,
+        max: None,
+        named: Map::new(&env),
+        signed_count: 0,
+    });
+}
+
+fn setup_near_max(env: &Env, mode: OverflowMode) -> IncrementClient<'_> {
+    env.mock_all_auths();
+    let (_, client) = setup_with_mode(env, mode);
+    client.import_state(&CounterState {
+        count: u32::MAX - 1,
+        step: 1,
+        max: None,
+        named: Map::new(env),
+        signed_count: 0,
+    });
+    client
+}
+
+#[test]
+fn test_overflow_mode_set_at_construction() {
+    let env = Env::default();
+    for mode in [
+        OverflowMode::Panic,
+        OverflowMode::Saturate,
+        OverflowMode::Wrap,
+    ] {
+        let (_, client) = setup_with_mode(&env, mode);
+        assert_eq!(client.get_overflow_mode(), mode);
+    }
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1)")]
+fn test_overflow_mode_panic() {
+    let env = Env::default();
+    let client = setup_near_max(&env, OverflowMode::Panic);
+    let caller = Address::generate(&env);
+
+    assert_eq!(client.increment(&caller), u32::MAX);
+    client.increment(&caller);
+}
+
+#[test]
+fn test_overflow_mode_saturate() {
+    let env = Env::default();
+    let client = setup_near_max(&env, OverflowMode::Saturate);
+    let caller = Address::generate(&env);
+
+    assert_eq!(client.increment(&caller), u32::MAX);
+    assert_eq!(client.increment(&caller), u32::MAX);
+}
+
+#[test]
+fn test_overflow_mode_wrap() {
+    let env = Env::default();
+    let client = setup_near_max(&env, OverflowMode::Wrap);
+    let caller = Address::generate(&env);
+
+    assert_eq!(client.increment(&caller), u32::MAX);
+    assert_eq!(client.increment(&caller), 0);
+    assert_eq!(client.increment(&caller), 1);
+}
+
+#[test]
+fn test_decrement_burn_disabled_by_default() {
+    let env = Env::default();
+    let (_, client) = setup(&env);
+
+    assert_eq!(
+        client.get_decrement_burn(),
+        BurnConfig {
+            amount: 0,
+            enabled: false,
+        }
+    );
+}
+
+#[test]
+fn test_decrement_burns_tokens() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let token_id = env.register(MockToken, ());
+    let token = MockTokenClient::new(&env, &token_id);
+    client.set_token(&token_id);
+    client.set_decrement_burn(&10, &true);
+
+    let caller = Address::generate(&env);
+    token.mint(&caller, &100);
+    client.increment(&caller);
+    client.increment(&caller);
+
+    assert_eq!(client.decrement(&caller), 1);
+    assert_eq!(token.balance(&caller), 90);
+    assert_eq!(token.total_supply(), 90);
+
+    // Turning the flag off keeps the amount but stops burning.
+    client.set_decrement_burn(&10, &false);
+    assert_eq!(client.decrement(&caller), 0);
+    assert_eq!(token.balance(&caller), 90);
+    assert_eq!(token.total_supply(), 90);
+}
+
+#[test]
+#[should_panic]
+fn test_decrement_burn_without_balance() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let caller = Address::generate(&env);
+    let token_id = env.register(MockToken, ());
+    client.set_token(&token_id);
+    client.set_decrement_burn(&10, &true);
+
+    client.increment(&caller);
+    client.decrement(&Address::generate(&env));
+}
+
+#[test]
+fn test_increment_for() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    assert_eq!(client.increment_for(&alice), 1);
+    assert_eq!(
+        env.auths(),
+        std::vec![(
+            alice.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    client.address.clone(),
+                    Symbol::new(&env, "increment_for"),
+                    (&alice,).into_val(&env),
+                )),
+                sub_invocations: std::vec![],
+            }
+        )]
+    );
+    assert_eq!(client.increment_for(&alice), 2);
+    assert_eq!(client.increment_for(&bob), 1);
+
+    assert_eq!(client.get_count_for(&alice), 2);
+    assert_eq!(client.get_count_for(&bob), 1);
+    // The global counter is independent of per-user counters.
+    assert_eq!(client.get_count(), 0);
+}
+
+#[test]
+#[should_panic]
+fn test_increment_for_requires_auth() {
+    let env = Env::default();
+    let (_, client) = setup(&env);
+
+    client.increment_for(&Address::generate(&env));
+}
+
+#[test]
+fn test_increment_for_many() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let carol = Address::generate(&env);
+    client.increment_for(&bob);
+
+    let users = vec![&env, alice.clone(), bob.clone(), carol.clone()];
+    assert_eq!(client.increment_for_many(&users), vec![&env, 1, 2, 1]);
+    // Every listed user authorized the batch.
+    let signers: std::vec::Vec<Address> = env.auths().into_iter().map(|(a, _)| a).collect();
+    assert_eq!(
+        signers,
+        std::vec![alice.clone(), bob.clone(), carol.clone()]
+    );
+
+    assert_eq!(client.get_count_for(&alice), 1);
+    assert_eq!(client.get_count_for(&bob), 2);
+    assert_eq!(client.get_count_for(&carol), 1);
+    assert_eq!(client.get_count(), 0);
+}
+
+#[test]
+fn test_increment_for_many_repeated_user() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    let users = vec![&env, alice.clone(), bob.clone(), alice.clone()];
+    assert_eq!(client.increment_for_many(&users), vec![&env, 1, 1, 2]);
+    // alice authorizes once, however often the address is listed.
+    let signers: std::vec::Vec<Address> = env.auths().into_iter().map(|(a, _)| a).collect();
+    assert_eq!(signers, std::vec![alice.clone(), bob.clone()]);
+    assert_eq!(client.get_count_for(&alice), 2);
+    assert_eq!(client.get_count_for(&bob), 1);
+}
+
+#[test]
+#[should_panic]
+fn test_increment_for_many_requires_every_auth() {
+    let env = Env::default();
+    let (_, client) = setup(&env);
+    let alice = Address::generate(&env);
+    let users = vec![&env, alice.clone(), Address::generate(&env)];
+
+    // Only alice signs, so bob's increment is rejected.
+    client
+        .mock_auths(&[MockAuth {
+            address: &alice,
+            invoke: &MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "increment_for_many",
+                args: (users.clone(),).into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .increment_for_many(&users);
+}
+
+#[test]
+fn test_increment_by() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let caller = Address::generate(&env);
+
+    assert_eq!(client.increment_by(&caller, &5), 5);
+    assert_eq!(client.increment_by(&caller, &0), 5);
+    assert_eq!(client.increment_by(&caller, &10), 15);
+    assert_eq!(client.get_count(), 15);
+}
+
+#[test]
+fn test_increment_by_overflow() {
+    let env = Env::default();
+    let client = setup_near_max(&env, OverflowMode::Panic);
+    let caller = Address::generate(&env);
+
+    assert_eq!(
+        client.try_increment_by(&caller, &2),
+        Err(Ok(CounterError::Overflow))
+    );
+    assert_eq!(client.get_count(), u32::MAX - 1);
+    assert_eq!(client.increment_by(&caller, &1), u32::MAX);
+}
+
+#[test]
+fn test_increment_by_respects_overflow_mode() {
+    let env = Env::default();
+    let client = setup_near_max(&env, OverflowMode::Saturate);
+    let caller = Address::generate(&env);
+    assert_eq!(client.increment_by(&caller, &10), u32::MAX);
+
+    let env = Env::default();
+    let client = setup_near_max(&env, OverflowMode::Wrap);
+    let caller = Address::generate(&env);
+    assert_eq!(client.increment_by(&caller, &3), 1);
+}
+
+#[test]
+fn test_decrement_by() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let caller = Address::generate(&env);
+
+    client.increment_by(&caller, &10);
+    assert_eq!(client.decrement_by(&caller, &3), 7);
+    // Saturates at zero rather than underflowing.
+    assert_eq!(client.decrement_by(&caller, &100), 0);
+    assert_eq!(client.get_count(), 0);
+}
+
+#[test]
+fn test_decrement_by_burns_per_unit() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let token_id = env.register(MockToken, ());
+    let token = MockTokenClient::new(&env, &token_id);
+    client.set_token(&token_id);
+    client.set_decrement_burn(&10, &true);
+
+    let caller = Address::generate(&env);
+    token.mint(&caller, &100);
+    client.increment_by(&caller, &5);
+
+    assert_eq!(client.decrement_by(&caller, &3), 2);
+    assert_eq!(token.balance(&caller), 70);
+    assert_eq!(token.total_supply(), 70);
+
+    // Only the two units actually removed are burned, and none at zero.
+    assert_eq!(client.decrement_by(&caller, &100), 0);
+    assert_eq!(token.balance(&caller), 50);
+    assert_eq!(client.decrement(&caller), 0);
+    assert_eq!(token.balance(&caller), 50);
+    assert_eq!(token.total_supply(), 50);
+}
+
+#[test]
+fn test_decrement_burns_per_unit_of_step() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let token_id = env.register(MockToken, ());
+    let token = MockTokenClient::new(&env, &token_id);
+    client.set_token(&token_id);
+    client.set_decrement_burn(&10, &true);
+    client.set_step(&5);
+
+    let caller = Address::generate(&env);
+    token.mint(&caller, &100);
+    client.increment_by(&caller, &10);
+
+    // A step of 5 costs the same as decrementing by 5.
+    assert_eq!(client.decrement(&caller), 5);
+    assert_eq!(token.balance(&caller), 50);
+    assert_eq!(client.decrement_by(&caller, &5), 0);
+    assert_eq!(token.balance(&caller), 0);
+}
+
+#[test]
+fn test_set_step() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, client) = setup(&env);
+    let caller = Address::generate(&env);
+    assert_eq!(client.get_step(), 1);
+
+    client.set_step(&5);
+    assert_eq!(env.auths()[0].0, admin);
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (symbol_short!("set_step"),).into_val(&env),
+                (1u32, 5u32).into_val(&env),
+            ),
+        ]
+    );
+    assert_eq!(client.get_step(), 5);
+
+    assert_eq!(client.increment(&caller), 5);
+    assert_eq!(client.increment(&caller), 10);
+    assert_eq!(client.decrement(&caller), 5);
+    // Decrement by a step larger than the count still stops at zero.
+    client.set_step(&7);
+    assert_eq!(client.decrement(&caller), 0);
+}
+
+#[test]
+fn test_set_step_rejects_zero() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+
+    assert_eq!(client.try_set_step(&0), Err(Ok(CounterError::InvalidStep)));
+    assert_eq!(client.get_step(), 1);
+}
+
+#[test]
+#[should_panic]
+fn test_set_step_requires_admin() {
+    let env = Env::default();
+    let (_, client) = setup(&env);
+
+    client.set_step(&2);
+}
+
+#[test]
+fn test_export_import_step() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, source) = setup(&env);
+    let caller = Address::generate(&env);
+    source.set_step(&3);
+    source.increment(&caller);
+
+    let (_, target) = setup(&env);
+    target.import_state(&source.export_state());
+    assert_eq!(target.get_step(), 3);
+    assert_eq!(target.increment(&caller), 6);
+}
+
+#[test]
+fn test_set_max() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, client) = setup(&env);
+    let caller = Address::generate(&env);
+    assert_eq!(client.get_max(), None);
+
+    client.set_max(&2);
+    assert_eq!(env.auths()[0].0, admin);
+    assert_eq!(client.get_max(), Some(2));
+
+    assert_eq!(client.increment(&caller), 1);
+    assert_eq!(client.increment(&caller), 2);
+    assert_eq!(
+        client.try_increment(&caller),
+        Err(Ok(CounterError::LimitReached))
+    );
+    assert_eq!(client.get_count(), 2);
+}
+
+#[test]
+fn test_max_applies_to_increment_by() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let caller = Address::generate(&env);
+    client.set_max(&10);
+
+    assert_eq!(client.increment_by(&caller, &10), 10);
+    assert_eq!(
+        client.try_increment_by(&caller, &1),
+        Err(Ok(CounterError::LimitReached))
+    );
+
+    // Raising the bound lets the counter grow again.
+    client.set_max(&11);
+    assert_eq!(client.increment_by(&caller, &1), 11);
+}
+
+#[test]
+fn test_max_with_step() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let caller = Address::generate(&env);
+    client.set_step(&3);
+    client.set_max(&7);
+
+    assert_eq!(client.increment(&caller), 3);
+    assert_eq!(client.increment(&caller), 6);
+    // A partial step would overshoot the bound, so it is rejected outright.
+    assert_eq!(
+        client.try_increment(&caller),
+        Err(Ok(CounterError::LimitReached))
+    );
+    assert_eq!(client.get_count(), 6);
+}
+
+#[test]
+#[should_panic]
+fn test_set_max_requires_admin() {
+    let env = Env::default();
+    let (_, client) = setup(&env);
+
+    client.set_max(&5);
+}
+
+#[test]
+fn test_counter_change_events() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, client) = setup(&env);
+    let caller = Address::generate(&env);
+
+    client.increment(&caller);
+    assert_eq!(
+        env.auths(),
+        std::vec![(
+            caller.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    client.address.clone(),
+                    symbol_short!("increment"),
+                    (&caller,).into_val(&env),
+                )),
+                sub_invocations: std::vec![],
+            }
+        )]
+    );
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (symbol_short!("increment"), caller.clone()).into_val(&env),
+                (0u32, 1u32).into_val(&env),
+            ),
+        ]
+    );
+
+    client.increment_by(&caller, &4);
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (symbol_short!("increment"), caller.clone()).into_val(&env),
+                (1u32, 5u32).into_val(&env),
+            ),
+        ]
+    );
+
+    client.decrement(&caller);
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (symbol_short!("decrement"), caller.clone()).into_val(&env),
+                (5u32, 4u32).into_val(&env),
+            ),
+        ]
+    );
+
+    client.reset(&admin);
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (symbol_short!("reset"), admin.clone()).into_val(&env),
+                (4u32, 0u32).into_val(&env),
+            ),
+        ]
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_increment_requires_auth() {
+    let env = Env::default();
+    let (_, client) = setup(&env);
+
+    client.increment(&Address::generate(&env));
+}
+
+#[test]
+fn test_named_counters() {
+    let env = Env::default();
+    let (_, client) = setup(&env);
+    let likes = symbol_short!("likes");
+    let views = symbol_short!("views");
+    assert!(client.list_counters().is_empty());
+
+    assert_eq!(client.increment_named(&likes), 1);
+    assert_eq!(client.increment_named(&views), 1);
+    assert_eq!(client.increment_named(&likes), 2);
+
+    assert_eq!(client.get_named(&likes), 2);
+    assert_eq!(client.get_named(&views), 1);
+    assert_eq!(client.get_named(&symbol_short!("unknown")), 0);
+    assert_eq!(client.list_counters(), vec![&env, likes, views]);
+    // Named counters don't touch the global counter.
+    assert_eq!(client.get_count(), 0);
+}
+
+#[test]
+fn test_named_counters_are_capped() {
+    let env = Env::default();
+    let (_, client) = setup(&env);
+    for i in 0..MAX_NAMED_COUNTERS {
+        let name = Symbol::new(&env, &std::format!("counter_{i}"));
+        assert_eq!(client.increment_named(&name), 1);
+    }
+
+    assert_eq!(
+        client.try_increment_named(&symbol_short!("extra")),
+        Err(Ok(CounterError::TooManyCounters))
+    );
+    assert_eq!(client.list_counters().len(), MAX_NAMED_COUNTERS);
+    // Existing counters keep counting.
+    assert_eq!(client.increment_named(&symbol_short!("counter_0")), 2);
+}
+
+#[test]
+fn test_export_import_named() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, source) = setup(&env);
+    let likes = symbol_short!("likes");
+    let views = symbol_short!("views");
+    source.increment_named(&likes);
+    source.increment_named(&likes);
+    source.increment_named(&views);
+
+    let state = source.export_state();
+    assert_eq!(
+        state.named,
+        map![&env, (likes.clone(), 2u32), (views.clone(), 1u32)]
+    );
+
+    let (_, target) = setup(&env);
+    target.increment_named(&symbol_short!("stale"));
+    target.import_state(&state);
+    assert_eq!(target.export_state(), state);
+    assert_eq!(target.list_counters(), vec![&env, likes.clone(), views]);
+    assert_eq!(target.increment_named(&likes), 3);
+}
+
+#[test]
+fn test_history() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, client) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    env.ledger().set_sequence_number(100);
+    client.increment(&alice);
+    env.ledger().set_sequence_number(101);
+    client.increment_by(&bob, &5);
+    client.decrement_by(&alice, &2);
+    env.ledger().set_sequence_number(102);
+    client.reset(&admin);
+
+    assert_eq!(
+        client.get_history(&0, &10),
+        vec![
+            &env,
+            HistoryEntry {
+                ledger: 100,
+                invoker: alice.clone(),
+                delta: 1,
+            },
+            HistoryEntry {
+                ledger: 101,
+                invoker: bob.clone(),
+                delta: 5,
+            },
+            HistoryEntry {
+                ledger: 101,
+                invoker: alice.clone(),
+                delta: -2,
+            },
+            HistoryEntry {
+                ledger: 102,
+                invoker: admin.clone(),
+                delta: -4,
+            },
+        ]
+    );
+
+    // Pagination over the retained entries.
+    assert_eq!(
+        client.get_history(&1, &2),
+        vec![
+            &env,
+            HistoryEntry {
+                ledger: 101,
+                invoker: bob,
+                delta: 5,
+            },
+            HistoryEntry {
+                ledger: 101,
+                invoker: alice,
+                delta: -2,
+            },
+        ]
+    );
+    assert!(client.get_history(&4, &10).is_empty());
+}
+
+#[test]
+fn test_history_is_bounded() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let caller = Address::generate(&env);
+
+    for i in 0..HISTORY_SIZE + 5 {
+        env.ledger().set_sequence_number(i);
+        client.increment(&caller);
+    }
+
+    let history = client.get_history(&0, &u32::MAX);
+    assert_eq!(history.len(), HISTORY_SIZE);
+    // The oldest five entries were overwritten.
+    assert_eq!(history.first().unwrap().ledger, 5);
+    assert_eq!(history.last().unwrap().ledger, HISTORY_SIZE + 4);
+}
+
+fn persistent_ttl(env: &Env, client: &IncrementClient, key: &DataKey) -> u32 {
+    env.as_contract(&client.address, || env.storage().persistent().get_ttl(key))
+}
+
+#[test]
+fn test_count_ttl_extended_on_write() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let caller = Address::generate(&env);
+
+    client.increment(&caller);
+    assert_eq!(persistent_ttl(&env, &client, &DataKey::Count), BUMP_AMOUNT);
+}
+
+#[test]
+fn test_count_ttl_extended_on_read() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let caller = Address::generate(&env);
+    client.increment(&caller);
+
+    // Still above the threshold: reading leaves the TTL alone.
+    env.ledger()
+        .with_mut(|li| li.sequence_number += BUMP_AMOUNT - BUMP_THRESHOLD - 1);
+    assert_eq!(client.get_count(), 1);
+    assert_eq!(
+        persistent_ttl(&env, &client, &DataKey::Count),
+        BUMP_THRESHOLD + 1
+    );
+
+    // Below the threshold: reading extends it back to the full amount.
+    env.ledger().with_mut(|li| li.sequence_number += 2);
+    assert_eq!(client.get_count(), 1);
+    assert_eq!(persistent_ttl(&env, &client, &DataKey::Count), BUMP_AMOUNT);
+}
+
+#[test]
+fn test_user_and_named_ttl_extended() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let user = Address::generate(&env);
+    let name = symbol_short!("likes");
+
+    client.increment_for(&user);
+    client.increment_named(&name);
+    assert_eq!(
+        persistent_ttl(&env, &client, &DataKey::UserCount(0, user)),
+        BUMP_AMOUNT
+    );
+    assert_eq!(
+        persistent_ttl(&env, &client, &DataKey::Named(name)),
+        BUMP_AMOUNT
+    );
+}
+
+#[test]
+fn test_index_and_history_ttl_extended() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let caller = Address::generate(&env);
+
+    client.increment_named(&symbol_short!("likes"));
+    client.increment(&caller);
+    for key in [DataKey::Names, DataKey::History(0), DataKey::HistoryLen] {
+        assert_eq!(persistent_ttl(&env, &client, &key), BUMP_AMOUNT);
+    }
+}
+
+#[test]
+fn test_try_increment_overflow() {
+    let env = Env::default();
+    let client = setup_near_max(&env, OverflowMode::Panic);
+    let caller = Address::generate(&env);
+
+    assert_eq!(client.try_increment(&caller), Ok(Ok(u32::MAX)));
+    assert_eq!(
+        client.try_increment(&caller),
+        Err(Ok(CounterError::Overflow))
+    );
+    assert_eq!(client.get_count(), u32::MAX);
+}
+
+#[test]
+fn test_try_increment_for_and_named_overflow() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let user = Address::generate(&env);
+    let name = symbol_short!("likes");
+
+    // Seed the user and named counters at the maximum.
+    client.increment_for(&user);
+    client.increment_named(&name);
+    env.as_contract(&client.address, || {
+        let storage = env.storage().persistent();
+        storage.set(&DataKey::UserCount(0, user.clone()), &u32::MAX);
+        storage.set(&DataKey::Named(name.clone()), &u32::MAX);
+    });
+
+    assert_eq!(
+        client.try_increment_for(&user),
+        Err(Ok(CounterError::Overflow))
+    );
+    assert_eq!(
+        client.try_increment_named(&name),
+        Err(Ok(CounterError::Overflow))
+    );
+    assert_eq!(client.get_count_for(&user), u32::MAX);
+    assert_eq!(client.get_named(&name), u32::MAX);
+}
+
+#[test]
+fn test_pause() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, client) = setup(&env);
+    let caller = Address::generate(&env);
+    client.increment(&caller);
+    assert!(!client.is_paused());
+
+    client.pause();
+    assert_eq!(env.auths()[0].0, admin);
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (symbol_short!("paused"),).into_val(&env),
+                ().into_val(&env),
+            ),
+        ]
+    );
+    assert!(client.is_paused());
+
+    let paused = Err(Ok(CounterError::ContractPaused));
+    assert_eq!(client.try_increment(&caller), paused);
+    assert_eq!(client.try_decrement(&caller), paused);
+    assert_eq!(client.try_increment_by(&caller, &2), paused);
+    assert_eq!(client.try_decrement_by(&caller, &2), paused);
+    assert_eq!(client.try_increment_for(&caller), paused);
+    assert_eq!(client.try_increment_named(&symbol_short!("likes")), paused);
+    assert_eq!(client.try_increment_if_holder(&caller, &0), paused);
+    let paused_batch = Err(Ok(CounterError::ContractPaused));
+    assert_eq!(client.try_increment_times(&caller, &2), paused_batch);
+    assert_eq!(
+        client.try_increment_for_many(&vec![&env, caller.clone()]),
+        paused_batch
+    );
+    let paused_signed = Err(Ok(CounterError::ContractPaused));
+    assert_eq!(client.try_signed_increment(&caller), paused_signed);
+    assert_eq!(client.try_signed_decrement(&caller), paused_signed);
+    assert_eq!(client.get_count(), 1);
+    assert_eq!(client.get_signed_count(), 0);
+
+    client.unpause();
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (symbol_short!("unpaused"),).into_val(&env),
+                ().into_val(&env),
+            ),
+        ]
+    );
+    assert!(!client.is_paused());
+    assert_eq!(client.increment(&caller), 2);
+}
+
+#[test]
+#[should_panic]
+fn test_pause_requires_admin() {
+    let env = Env::default();
+    let (_, client) = setup(&env);
+
+    client.pause();
+}
+
+#[test]
+fn test_increment_pays_fee() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let token = create_token(&env);
+    let tokens = TokenClient::new(&env, &token.address);
+    let treasury = Address::generate(&env);
+    client.set_token(&token.address);
+    assert_eq!(client.get_increment_fee(), None);
+
+    client.set_increment_fee(&10, &treasury);
+    assert_eq!(
+        client.get_increment_fee(),
+        Some(FeeConfig {
+            amount: 10,
+            treasury: treasury.clone(),
+        })
+    );
+
+    let caller = Address::generate(&env);
+    token.mint(&caller, &25);
+    assert_eq!(client.increment(&caller), 1);
+    assert_eq!(client.increment(&caller), 2);
+    assert_eq!(tokens.balance(&caller), 5);
+    assert_eq!(tokens.balance(&treasury), 20);
+
+    // Clearing the fee makes increments free again.
+    client.clear_increment_fee();
+    assert_eq!(client.increment(&caller), 3);
+    assert_eq!(tokens.balance(&caller), 5);
+}
+
+#[test]
+fn test_increment_by_pays_fee_per_unit() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let token = create_token(&env);
+    let tokens = TokenClient::new(&env, &token.address);
+    let treasury = Address::generate(&env);
+    client.set_token(&token.address);
+    client.set_increment_fee(&10, &treasury);
+
+    let caller = Address::generate(&env);
+    token.mint(&caller, &100);
+    assert_eq!(client.increment_by(&caller, &3), 3);
+    assert_eq!(tokens.balance(&caller), 70);
+    assert_eq!(tokens.balance(&treasury), 30);
+}
+
+#[test]
+fn test_increment_fee_follows_actual_change() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let token = create_token(&env);
+    let tokens = TokenClient::new(&env, &token.address);
+    let treasury = Address::generate(&env);
+    client.set_token(&token.address);
+    client.set_increment_fee(&10, &treasury);
+    client.set_step(&5);
+
+    let caller = Address::generate(&env);
+    token.mint(&caller, &100);
+    // A step of 5 costs the same as incrementing by 5.
+    assert_eq!(client.increment(&caller), 5);
+    assert_eq!(tokens.balance(&treasury), 50);
+    assert_eq!(client.increment_by(&caller, &5), 10);
+    assert_eq!(tokens.balance(&treasury), 100);
+    assert_eq!(tokens.balance(&caller), 0);
+}
+
+#[test]
+fn test_increment_if_holder_pays_fee() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let token = create_token(&env);
+    let tokens = TokenClient::new(&env, &token.address);
+    let treasury = Address::generate(&env);
+    client.set_token(&token.address);
+    client.set_increment_fee(&10, &treasury);
+
+    let caller = Address::generate(&env);
+    token.mint(&caller, &100);
+    assert_eq!(client.increment_if_holder(&caller, &0), 1);
+    assert_eq!(tokens.balance(&caller), 90);
+    assert_eq!(tokens.balance(&treasury), 10);
+}
+
+#[test]
+#[should_panic]
+fn test_increment_fee_without_funds() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let token = create_token(&env);
+    client.set_token(&token.address);
+    client.set_increment_fee(&10, &Address::generate(&env));
+
+    let caller = Address::generate(&env);
+    token.mint(&caller, &5);
+    client.increment(&caller);
+}
+
+#[test]
+fn test_signed_counter_goes_negative() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let caller = Address::generate(&env);
+
+    assert_eq!(client.get_signed_count(), 0);
+    assert_eq!(client.signed_decrement(&caller), -1);
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (symbol_short!("signed"), caller.clone()).into_val(&env),
+                (0_i128, -1_i128).into_val(&env),
+            ),
+        ]
+    );
+    assert_eq!(client.signed_decrement(&caller), -2);
+    assert_eq!(client.signed_increment(&caller), -1);
+    assert_eq!(client.get_signed_count(), -1);
+
+    // The signed counter is independent of the unsigned one.
+    assert_eq!(client.get_count(), 0);
+}
+
+#[test]
+fn test_signed_counter_uses_step() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let caller = Address::generate(&env);
+    client.set_step(&5);
+
+    assert_eq!(client.signed_decrement(&caller), -5);
+    assert_eq!(client.signed_increment(&caller), 0);
+    assert_eq!(client.signed_increment(&caller), 5);
+}
+
+#[test]
+fn test_emergency_reset_clears_signed_counter() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    client.signed_decrement(&Address::generate(&env));
+
+    client.emergency_reset();
+    assert_eq!(client.get_signed_count(), 0);
+}
+
+#[test]
+fn test_increment_times() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let caller = Address::generate(&env);
+    client.set_step(&2);
+
+    assert_eq!(client.increment_times(&caller, &3), vec![&env, 2, 4, 6]);
+    assert_eq!(client.get_count(), 6);
+    assert_eq!(client.get_history(&0, &10).len(), 3);
+    assert_eq!(client.increment_times(&caller, &0), vec![&env]);
+}
+
+#[test]
+fn test_increment_times_saturates() {
+    let env = Env::default();
+    let client = setup_near_max(&env, OverflowMode::Saturate);
+    let caller = Address::generate(&env);
+
+    assert_eq!(
+        client.increment_times(&caller, &3),
+        vec![&env, u32::MAX, u32::MAX, u32::MAX]
+    );
+}
+
+#[test]
+fn test_increment_times_stops_at_max() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let caller = Address::generate(&env);
+    client.set_max(&2);
+
+    assert_eq!(
+        client.try_increment_times(&caller, &3),
+        Err(Ok(CounterError::LimitReached))
+    );
+    // The failed call is rolled back as a whole.
+    assert_eq!(client.get_count(), 0);
+}
+
+#[test]
+fn test_increment_times_pays_fee_per_increment() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let token = create_token(&env);
+    let treasury = Address::generate(&env);
+    client.set_token(&token.address);
+    client.set_increment_fee(&10, &treasury);
+
+    let caller = Address::generate(&env);
+    token.mint(&caller, &30);
+    client.increment_times(&caller, &3);
+    assert_eq!(
+        TokenClient::new(&env, &token.address).balance(&treasury),
+        30
+    );
+}
+
+#[test]
+fn test_user_and_named_counter_events() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let user = Address::generate(&env);
+    let name = symbol_short!("likes");
+
+    client.increment_for(&user);
+    client.increment_for(&user);
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (Symbol::new(&env, "increment_for"), user.clone()).into_val(&env),
+                (1_u32, 2_u32).into_val(&env),
+            ),
+        ]
+    );
+
+    client.increment_named(&name);
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (Symbol::new(&env, "increment_named"), name).into_val(&env),
+                (0_u32, 1_u32).into_val(&env),
+            ),
+        ]
+    );
 }
@@ -0,0 +1,114 @@
+#![no_std]
+use soroban_sdk::{
+    contract, contractclient, contracterror, contractimpl, contracttype, token, Address, Env,
+    Symbol, Vec,
+};
+
+/// Errors returned by the increment contract, mirroring its `CounterError`
+/// codes so they surface unchanged through `increment`.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum CounterError {
+    Overflow = 1,
+    InvalidStep = 2,
+    LimitReached = 3,
+    NotAdmin = 4,
+    ContractPaused = 5,
+    InsufficientBalance = 6,
+    TooManyCounters = 7,
+}
+
+/// The subset of the increment contract interface used by this contract.
+#[contractclient(name = "CounterClient")]
+pub trait Counter {
+    fn increment(env: Env, caller: Address) -> Result<u32, CounterError>;
+}
+
+#[contracttype]
+#[derive(Clone)]
+enum DataKey {
+    Token,
+    Treasury,
+    Counter,
+    Fee,
+}
+
+/// Pay-per-action counter: every increment charges a token fee that is
+/// sent to a treasury before the increment contract is advanced.
+#[contract]
+pub struct PaidCounter;
+
+#[contractimpl]
+impl PaidCounter {
+    /// Constructor - stores the fee token, treasury, counter contract and fee.
+    pub fn __constructor(env: Env, token: Address, treasury: Address, counter: Address, fee: i128) {
+        if fee < 0 {
+            panic!("Fee must not be negative");
+        }
+        let storage = env.storage().instance();
+        storage.set(&DataKey::Token, &token);
+        storage.set(&DataKey::Treasury, &treasury);
+        storage.set(&DataKey::Counter, &counter);
+        storage.set(&DataKey::Fee, &fee);
+    }
+
+    /// Charge `caller` the fee and increment the counter, returning its new
+    /// value. Errors from the counter are returned as-is, and the fee is only
+    /// kept if the increment succeeds.
+    pub fn increment(env: Env, caller: Address) -> Result<u32, CounterError> {
+        caller.require_auth();
+
+        let fee = Self::fee(env.clone());
+        if fee > 0 {
+            token::Client::new(&env, &Self::token(env.clone())).transfer(
+                &caller,
+                &Self::treasury(env.clone()),
+                &fee,
+            );
+        }
+
+        match CounterClient::new(&env, &Self::counter(env.clone())).try_increment(&caller) {
+            Ok(Ok(count)) => Ok(count),
+            Err(Ok(error)) => Err(error),
+            _ => panic!("Unexpected response from counter"),
+        }
+    }
+
+    /// Get the token the fee is paid in.
+    pub fn token(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Token)
+            .unwrap_or_else(|| panic!("Token not set"))
+    }
+
+    /// Get the address that receives fees.
+    pub fn treasury(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Treasury)
+            .unwrap_or_else(|| panic!("Treasury not set"))
+    }
+
+    /// Get the increment contract being driven.
+    pub fn counter(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Counter)
+            .unwrap_or_else(|| panic!("Counter not set"))
+    }
+
+    /// Get the fee charged per increment.
+    pub fn fee(env: Env) -> i128 {
+        env.storage().instance().get(&DataKey::Fee).unwrap_or(0)
+    }
+
+    /// Returns the standard interfaces this contract implements.
+    pub fn supported_interfaces(env: Env) -> Vec<Symbol> {
+        Vec::new(&env)
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,93 @@
+use increment::{Increment, IncrementClient, OverflowMode};
+use soroban_sdk::{
+    testutils::Address as _,
+    token::{StellarAssetClient, TokenClient},
+    Address, Env,
+};
+
+use crate::{CounterError, PaidCounter, PaidCounterClient};
+
+struct Setup<'a> {
+    token: StellarAssetClient<'a>,
+    treasury: Address,
+    counter: IncrementClient<'a>,
+    client: PaidCounterClient<'a>,
+}
+
+fn setup(env: &Env, fee: i128) -> Setup<'_> {
+    env.mock_all_auths();
+    let issuer = Address::generate(env);
+    let sac = env.register_stellar_asset_contract_v2(issuer);
+    let token = StellarAssetClient::new(env, &sac.address());
+
+    let admin = Address::generate(env);
+    let counter_id = env.register(Increment, (&admin, OverflowMode::Panic));
+    let counter = IncrementClient::new(env, &counter_id);
+
+    let treasury = Address::generate(env);
+    let contract_id = env.register(PaidCounter, (&sac.address(), &treasury, &counter_id, &fee));
+    let client = PaidCounterClient::new(env, &contract_id);
+
+    Setup {
+        token,
+        treasury,
+        counter,
+        client,
+    }
+}
+
+#[test]
+fn test_config() {
+    let env = Env::default();
+    let s = setup(&env, 10);
+
+    assert_eq!(s.client.token(), s.token.address);
+    assert_eq!(s.client.treasury(), s.treasury);
+    assert_eq!(s.client.counter(), s.counter.address);
+    assert_eq!(s.client.fee(), 10);
+}
+
+#[test]
+fn test_increment_charges_fee() {
+    let env = Env::default();
+    let s = setup(&env, 10);
+    let tokens = TokenClient::new(&env, &s.token.address);
+    let caller = Address::generate(&env);
+    s.token.mint(&caller, &25);
+
+    assert_eq!(s.client.increment(&caller), 1);
+    assert_eq!(s.client.increment(&caller), 2);
+
+    assert_eq!(tokens.balance(&caller), 5);
+    assert_eq!(tokens.balance(&s.treasury), 20);
+    assert_eq!(s.counter.get_count(), 2);
+}
+
+#[test]
+fn test_increment_returns_counter_error() {
+    let env = Env::default();
+    let s = setup(&env, 10);
+    let tokens = TokenClient::new(&env, &s.token.address);
+    let caller = Address::generate(&env);
+    s.token.mint(&caller, &25);
+    s.counter.pause();
+
+    assert_eq!(
+        s.client.try_increment(&caller),
+        Err(Ok(CounterError::ContractPaused))
+    );
+    // The failed call is rolled back, fee included.
+    assert_eq!(tokens.balance(&caller), 25);
+    assert_eq!(s.counter.get_count(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #10)")]
+fn test_increment_without_funds() {
+    let env = Env::default();
+    let s = setup(&env, 10);
+    let caller = Address::generate(&env);
+    s.token.mint(&caller, &5);
+
+    s.client.increment(&caller);
+}
@@ -0,0 +1,248 @@
+#![no_std]
+use soroban_sdk::{
+    contract, contracterror, contractimpl, contracttype, token, Address, Env, IntoVal, Symbol,
+    TryFromVal, Val, Vec,
+};
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum VaultError {
+    /// Token amounts and share counts must be positive.
+    InvalidAmount = 1,
+    /// The deposit is worth less than one share.
+    DepositTooSmall = 2,
+    /// The caller holds fewer shares than requested.
+    InsufficientShares = 3,
+    /// Yield cannot be added while no shares exist.
+    NoSharesOutstanding = 4,
+    /// The amounts involved are too large to price.
+    Overflow = 5,
+}
+
+/// Shares and assets the vault behaves as if it always held. Pricing
+/// against these virtual amounts means a donation through `add_yield` mostly
+/// accrues to the virtual shares, so a first depositor cannot inflate the
+/// share price enough to round later deposits down to almost nothing.
+const VIRTUAL_SHARES: i128 = 1000;
+const VIRTUAL_ASSETS: i128 = 1;
+
+const DAY_IN_LEDGERS: u32 = 17280;
+/// Share balances whose TTL falls below this many ledgers are extended.
+const BUMP_THRESHOLD: u32 = 7 * DAY_IN_LEDGERS;
+/// Number of ledgers share balances are extended to live for.
+const BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+
+#[contracttype]
+#[derive(Clone)]
+enum DataKey {
+    Token,
+    TotalShares,
+    TotalAssets,
+    Shares(Address),
+}
+
+/// Read a persistent vault entry, extending its TTL if it exists. The
+/// contract instance, which holds the totals, is extended too.
+fn read_persistent<V: TryFromVal<Env, Val>>(env: &Env, key: &DataKey) -> Option<V> {
+    env.storage()
+        .instance()
+        .extend_ttl(BUMP_THRESHOLD, BUMP_AMOUNT);
+
+    let storage = env.storage().persistent();
+    let value = storage.get(key);
+    if value.is_some() {
+        storage.extend_ttl(key, BUMP_THRESHOLD, BUMP_AMOUNT);
+    }
+    value
+}
+
+/// Write a persistent vault entry and extend its TTL, along with the
+/// contract instance.
+fn write_persistent<V: IntoVal<Env, Val>>(env: &Env, key: &DataKey, value: &V) {
+    env.storage()
+        .instance()
+        .extend_ttl(BUMP_THRESHOLD, BUMP_AMOUNT);
+
+    let storage = env.storage().persistent();
+    storage.set(key, value);
+    storage.extend_ttl(key, BUMP_THRESHOLD, BUMP_AMOUNT);
+}
+
+/// Compute `a * b / c`, failing with `Overflow` instead of trapping when the
+/// product does not fit in an `i128`.
+fn mul_div(a: i128, b: i128, c: i128) -> Result<i128, VaultError> {
+    Ok(a.checked_mul(b).ok_or(VaultError::Overflow)? / c)
+}
+
+/// Shares-based vault around a SEP-41 token.
+///
+/// Each share is a pro-rata claim on the tokens held by the vault, so yield
+/// added through `add_yield` increases the value of every outstanding share.
+/// Shares are priced against `VIRTUAL_SHARES` and `VIRTUAL_ASSETS` on top of
+/// the real totals, so an empty vault mints 1000 shares per token.
+#[contract]
+pub struct Vault;
+
+#[contractimpl]
+impl Vault {
+    /// Constructor - stores the token this vault accepts.
+    pub fn __constructor(env: Env, token: Address) {
+        env.storage().instance().set(&DataKey::Token, &token);
+    }
+
+    /// Get the token held by the vault.
+    pub fn token(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Token)
+            .unwrap_or_else(|| panic!("Token not set"))
+    }
+
+    /// Deposit `amount` tokens from `from`, returning the shares minted.
+    pub fn deposit(env: Env, from: Address, amount: i128) -> Result<i128, VaultError> {
+        from.require_auth();
+        if amount <= 0 {
+            return Err(VaultError::InvalidAmount);
+        }
+
+        let total_shares = Self::total_shares(env.clone());
+        let total_assets = Self::total_assets(env.clone());
+        let shares = mul_div(
+            amount,
+            total_shares + VIRTUAL_SHARES,
+            total_assets + VIRTUAL_ASSETS,
+        )?;
+        if shares == 0 {
+            return Err(VaultError::DepositTooSmall);
+        }
+        let new_total_shares = total_shares
+            .checked_add(shares)
+            .ok_or(VaultError::Overflow)?;
+        let new_total_assets = total_assets
+            .checked_add(amount)
+            .ok_or(VaultError::Overflow)?;
+
+        token::Client::new(&env, &Self::token(env.clone())).transfer(
+            &from,
+            &env.current_contract_address(),
+            &amount,
+        );
+
+        Self::set_shares(
+            &env,
+            &from,
+            Self::shares_of(env.clone(), from.clone()) + shares,
+        );
+        Self::set_totals(&env, new_total_shares, new_total_assets);
+
+        Ok(shares)
+    }
+
+    /// Redeem `shares` held by `to`, returning the tokens paid out.
+    pub fn withdraw(env: Env, to: Address, shares: i128) -> Result<i128, VaultError> {
+        to.require_auth();
+        if shares <= 0 {
+            return Err(VaultError::InvalidAmount);
+        }
+
+        let balance = Self::shares_of(env.clone(), to.clone());
+        if balance < shares {
+            return Err(VaultError::InsufficientShares);
+        }
+
+        let total_shares = Self::total_shares(env.clone());
+        let total_assets = Self::total_assets(env.clone());
+        let amount = mul_div(
+            shares,
+            total_assets + VIRTUAL_ASSETS,
+            total_shares + VIRTUAL_SHARES,
+        )?;
+
+        Self::set_shares(&env, &to, balance - shares);
+        Self::set_totals(&env, total_shares - shares, total_assets - amount);
+
+        token::Client::new(&env, &Self::token(env.clone())).transfer(
+            &env.current_contract_address(),
+            &to,
+            &amount,
+        );
+
+        Ok(amount)
+    }
+
+    /// Add `amount` tokens from `from` as yield without minting shares,
+    /// raising the value of every existing share.
+    pub fn add_yield(env: Env, from: Address, amount: i128) -> Result<(), VaultError> {
+        from.require_auth();
+        if amount <= 0 {
+            return Err(VaultError::InvalidAmount);
+        }
+        if Self::total_shares(env.clone()) == 0 {
+            return Err(VaultError::NoSharesOutstanding);
+        }
+        let total_assets = Self::total_assets(env.clone())
+            .checked_add(amount)
+            .ok_or(VaultError::Overflow)?;
+
+        token::Client::new(&env, &Self::token(env.clone())).transfer(
+            &from,
+            &env.current_contract_address(),
+            &amount,
+        );
+
+        env.storage()
+            .instance()
+            .set(&DataKey::TotalAssets, &total_assets);
+        Ok(())
+    }
+
+    /// Get the shares held by `id`.
+    pub fn shares_of(env: Env, id: Address) -> i128 {
+        read_persistent(&env, &DataKey::Shares(id)).unwrap_or(0)
+    }
+
+    /// Get the total number of shares outstanding.
+    pub fn total_shares(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::TotalShares)
+            .unwrap_or(0)
+    }
+
+    /// Get the total tokens held on behalf of shareholders.
+    pub fn total_assets(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::TotalAssets)
+            .unwrap_or(0)
+    }
+
+    /// Returns the standard interfaces this contract implements.
+    pub fn supported_interfaces(env: Env) -> Vec<Symbol> {
+        Vec::new(&env)
+    }
+}
+
+impl Vault {
+    fn set_shares(env: &Env, id: &Address, shares: i128) {
+        let key = DataKey::Shares(id.clone());
+        if shares == 0 {
+            env.storage().persistent().remove(&key);
+        } else {
+            write_persistent(env, &key, &shares);
+        }
+    }
+
+    fn set_totals(env: &Env, total_shares: i128, total_assets: i128) {
+        env.storage()
+            .instance()
+            .set(&DataKey::TotalShares, &total_shares);
+        env.storage()
+            .instance()
+            .set(&DataKey::TotalAssets, &total_assets);
+    }
+}
+
+#[cfg(test)]
+mod test;
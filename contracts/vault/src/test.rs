@@ -0,0 +1,189 @@
+use soroban_sdk::{
+    testutils::{storage::Persistent as _, Address as _},
+    token::{StellarAssetClient, TokenClient},
+    Address, Env,
+};
+
+use crate::{DataKey, Vault, VaultClient, VaultError, BUMP_AMOUNT};
+
+fn setup(env: &Env) -> (StellarAssetClient<'_>, VaultClient<'_>) {
+    env.mock_all_auths();
+    let issuer = Address::generate(env);
+    let sac = env.register_stellar_asset_contract_v2(issuer);
+    let token = StellarAssetClient::new(env, &sac.address());
+    let contract_id = env.register(Vault, (&sac.address(),));
+    (token, VaultClient::new(env, &contract_id))
+}
+
+#[test]
+fn test_first_deposit_mints_virtual_shares_per_token() {
+    let env = Env::default();
+    let (token, vault) = setup(&env);
+    let alice = Address::generate(&env);
+    token.mint(&alice, &1000);
+
+    assert_eq!(vault.deposit(&alice, &1000), 1_000_000);
+    assert_eq!(vault.shares_of(&alice), 1_000_000);
+    assert_eq!(vault.total_shares(), 1_000_000);
+    assert_eq!(vault.total_assets(), 1000);
+    assert_eq!(
+        TokenClient::new(&env, &token.address).balance(&vault.address),
+        1000
+    );
+}
+
+#[test]
+fn test_deposit_after_yield_receives_fewer_shares() {
+    let env = Env::default();
+    let (token, vault) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let yielder = Address::generate(&env);
+    token.mint(&alice, &1000);
+    token.mint(&bob, &300);
+    token.mint(&yielder, &500);
+
+    vault.deposit(&alice, &1000);
+    vault.add_yield(&yielder, &500);
+    assert_eq!(vault.total_assets(), 1500);
+
+    // 300 tokens at roughly 1.5 tokens per 1000 shares, priced against the
+    // virtual shares and assets.
+    assert_eq!(vault.deposit(&bob, &300), 200_066);
+    assert_eq!(vault.total_shares(), 1_200_066);
+    assert_eq!(vault.total_assets(), 1800);
+}
+
+#[test]
+fn test_withdraw_pays_out_pro_rata() {
+    let env = Env::default();
+    let (token, vault) = setup(&env);
+    let tokens = TokenClient::new(&env, &token.address);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let yielder = Address::generate(&env);
+    token.mint(&alice, &1000);
+    token.mint(&bob, &300);
+    token.mint(&yielder, &500);
+
+    vault.deposit(&alice, &1000);
+    vault.add_yield(&yielder, &500);
+    vault.deposit(&bob, &300);
+
+    // Rounding and the virtual assets leave one token of dust in the vault.
+    assert_eq!(vault.withdraw(&alice, &1_000_000), 1499);
+    assert_eq!(tokens.balance(&alice), 1499);
+    assert_eq!(vault.withdraw(&bob, &200_066), 300);
+    assert_eq!(tokens.balance(&bob), 300);
+
+    assert_eq!(vault.total_shares(), 0);
+    assert_eq!(vault.total_assets(), 1);
+    assert_eq!(tokens.balance(&vault.address), 1);
+}
+
+#[test]
+fn test_withdraw_more_than_held() {
+    let env = Env::default();
+    let (token, vault) = setup(&env);
+    let alice = Address::generate(&env);
+    token.mint(&alice, &100);
+
+    let shares = vault.deposit(&alice, &100);
+    assert_eq!(
+        vault.try_withdraw(&alice, &(shares + 1)),
+        Err(Ok(VaultError::InsufficientShares))
+    );
+}
+
+#[test]
+fn test_add_yield_to_empty_vault() {
+    let env = Env::default();
+    let (token, vault) = setup(&env);
+    let yielder = Address::generate(&env);
+    token.mint(&yielder, &100);
+
+    assert_eq!(
+        vault.try_add_yield(&yielder, &100),
+        Err(Ok(VaultError::NoSharesOutstanding))
+    );
+}
+
+#[test]
+fn test_invalid_amounts() {
+    let env = Env::default();
+    let (_, vault) = setup(&env);
+    let alice = Address::generate(&env);
+
+    assert_eq!(
+        vault.try_deposit(&alice, &0),
+        Err(Ok(VaultError::InvalidAmount))
+    );
+    assert_eq!(
+        vault.try_withdraw(&alice, &0),
+        Err(Ok(VaultError::InvalidAmount))
+    );
+    assert_eq!(
+        vault.try_add_yield(&alice, &0),
+        Err(Ok(VaultError::InvalidAmount))
+    );
+}
+
+#[test]
+fn test_first_depositor_inflation_is_unprofitable() {
+    let env = Env::default();
+    let (token, vault) = setup(&env);
+    let tokens = TokenClient::new(&env, &token.address);
+    let attacker = Address::generate(&env);
+    let victim = Address::generate(&env);
+    token.mint(&attacker, &1_000_001);
+    token.mint(&victim, &1_500_000);
+
+    // Deposit a single token, then donate a large amount to inflate the
+    // share price before the victim deposits.
+    let attacker_shares = vault.deposit(&attacker, &1);
+    vault.add_yield(&attacker, &1_000_000);
+    let victim_shares = vault.deposit(&victim, &1_500_000);
+    assert_eq!(victim_shares, 2999);
+
+    // Most of the donation is captured by the virtual shares, so the
+    // attacker gets back far less than they put in ...
+    assert_eq!(vault.withdraw(&attacker, &attacker_shares), 500_100);
+    assert!(tokens.balance(&attacker) < 1_000_001);
+
+    // ... and the victim keeps almost all of their deposit.
+    assert_eq!(vault.withdraw(&victim, &victim_shares), 1_499_801);
+}
+
+#[test]
+fn test_large_deposit_overflows_with_error() {
+    let env = Env::default();
+    let (token, vault) = setup(&env);
+    let alice = Address::generate(&env);
+    let amount: i128 = 1_000_000_000_000_000_000;
+    token.mint(&alice, &(2 * amount));
+
+    // 1e18 tokens mint 1e21 shares, so pricing a second deposit of the same
+    // size needs a product above i128::MAX.
+    vault.deposit(&alice, &amount);
+    assert_eq!(
+        vault.try_deposit(&alice, &amount),
+        Err(Ok(VaultError::Overflow))
+    );
+    assert_eq!(vault.total_assets(), amount);
+}
+
+#[test]
+fn test_shares_ttl_extended() {
+    let env = Env::default();
+    let (token, vault) = setup(&env);
+    let alice = Address::generate(&env);
+    token.mint(&alice, &100);
+
+    vault.deposit(&alice, &100);
+    let ttl = env.as_contract(&vault.address, || {
+        env.storage()
+            .persistent()
+            .get_ttl(&DataKey::Shares(alice.clone()))
+    });
+    assert_eq!(ttl, BUMP_AMOUNT);
+}
@@ -3,6 +3,7 @@
 const { execSync } = require('child_process')
 const fs = require('fs')
 const path = require('path')
+const { analyzeContractConstructor, collectConstructorArgs } = require('./contract-analyzer')
 
 const log = (message) => {
   console.log(`\n\x1b[1;32m[LOG] ${message}\x1b[0m`)
@@ -99,7 +100,9 @@ const detectContracts = () => {
       const cargoTomlPath = path.join(itemPath, 'Cargo.toml')
       const srcPath = path.join(itemPath, 'src')
       
-      if (fs.existsSync(cargoTomlPath) && fs.existsSync(srcPath)) {
+      // Library crates such as contracts/common build no WASM to deploy
+      if (fs.existsSync(cargoTomlPath) && fs.existsSync(srcPath) &&
+          fs.readFileSync(cargoTomlPath, 'utf8').includes('cdylib')) {
         contracts.push({
           name: item,
           path: itemPath
@@ -112,7 +115,7 @@ const detectContracts = () => {
   return contracts
 }
 
-const deployContract = (contractName, wasmPath) => {
+const deployContract = async (contractName, wasmPath, contractPath) => {
   log(`Deploying ${contractName} contract to localnet...`)
   
   try {
@@ -125,9 +128,19 @@ const deployContract = (contractName, wasmPath) => {
     const wasmHash = uploadOutput.trim()
     log(`${contractName} wasm hash: ${wasmHash}`)
     
-    // Deploy contract
+    // Analyze constructor arguments
+    const constructorAnalysis = analyzeContractConstructor(contractPath)
+    let constructorArgs = ''
+    
+    if (constructorAnalysis && constructorAnalysis.hasConstructor) {
+      constructorArgs = await collectConstructorArgs(contractName, constructorAnalysis.args)
+    }
+    
+    // Deploy contract with constructor arguments
+    const deployCmd = `stellar contract deploy --wasm-hash ${wasmHash} --source alice --network localnet --alias ${contractName}${constructorArgs ? ' -- ' + constructorArgs : ''}`
+    
     const deployOutput = execSync(
-      `stellar contract deploy --wasm-hash ${wasmHash} --source alice --network localnet --alias ${contractName}`,
+      deployCmd,
       { encoding: 'utf8' }
     )
     
@@ -288,10 +301,7 @@ const main = async () => {
     // Deploy each contract
     for (const contract of detectedContracts) {
       const wasmPath = `contracts/target/wasm32v1-none/release/${contract.name}.wasm`
-      const result = deployContract(
-        contract.name,
-        wasmPath
-      )
+      const result = await deployContract(contract.name, wasmPath, contract.path)
       if (result) {
         contracts[contract.name] = result
       }
@@ -247,7 +247,9 @@ const detectContracts = () => {
       const cargoTomlPath = path.join(itemPath, 'Cargo.toml')
       const srcPath = path.join(itemPath, 'src')
       
-      if (fs.existsSync(cargoTomlPath) && fs.existsSync(srcPath)) {
+      // Library crates such as contracts/common build no WASM to deploy
+      if (fs.existsSync(cargoTomlPath) && fs.existsSync(srcPath) &&
+          fs.readFileSync(cargoTomlPath, 'utf8').includes('cdylib')) {
         contracts.push({
           name: item,
           path: itemPath